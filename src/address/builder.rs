@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+
+use crate::{CountryCode, Metadata};
+
+use super::request::{CreateAddressRequest, JsonRequest};
+
+/// Builder for creating a create address request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateAddressBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateAddressBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the name of the person or company the address belongs to
+    pub fn name<'c>(self, name: &'c str) -> CreateAddressBuilderWithName<'a, 'b, 'c> {
+        CreateAddressBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name,
+        }
+    }
+}
+
+/// Builder for a create address request with a name set.
+pub struct CreateAddressBuilderWithName<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+}
+
+impl<'a, 'b, 'c> CreateAddressBuilderWithName<'a, 'b, 'c> {
+    /// sets the first address line
+    pub fn address_line_1<'d, 'e>(
+        self,
+        address_line_1: &'d str,
+    ) -> CreateAddressBuilderWithAddress<'a, 'b, 'c, 'd, 'e> {
+        CreateAddressBuilderWithAddress {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1,
+            address_line_2: None,
+        }
+    }
+}
+
+/// Builder for a create address request with addresses set.
+pub struct CreateAddressBuilderWithAddress<'a, 'b, 'c, 'd, 'e> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e> CreateAddressBuilderWithAddress<'a, 'b, 'c, 'd, 'e> {
+    /// sets the second address line
+    pub fn address_line_2(self, address_line_2: &'e str) -> Self {
+        Self {
+            address_line_2: Some(address_line_2),
+            ..self
+        }
+    }
+
+    /// sets the city
+    pub fn city<'f>(self, city: &'f str) -> CreateAddressBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f> {
+        CreateAddressBuilderWithCity {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city,
+        }
+    }
+}
+
+/// Builder for a create address request with a city set.
+pub struct CreateAddressBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+    city: &'f str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> CreateAddressBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f> {
+    /// sets the state
+    pub fn state<'g>(
+        self,
+        state: &'g str,
+    ) -> CreateAddressBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        CreateAddressBuilderWithState {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state,
+        }
+    }
+}
+
+/// Builder for a create address request with a state set.
+pub struct CreateAddressBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+    city: &'f str,
+    state: &'g str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> CreateAddressBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    /// sets the zip code
+    pub fn zip_code<'h, 'i, 'j, 'k, 'l, 'm>(
+        self,
+        zip_code: &'h str,
+    ) -> CreateAddressBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm> {
+        CreateAddressBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state: self.state,
+            zip_code,
+            company: None,
+            phone: None,
+            email: None,
+            description: None,
+            country: None,
+            metadata: None,
+        }
+    }
+}
+
+/// Builder for a create address request with a zip code set.
+pub struct CreateAddressBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+    city: &'f str,
+    state: &'g str,
+    zip_code: &'h str,
+    company: Option<&'i str>,
+    phone: Option<&'j str>,
+    email: Option<&'k str>,
+    description: Option<&'l str>,
+    country: Option<CountryCode>,
+    metadata: Option<Metadata<'m>>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm>
+    CreateAddressBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm>
+{
+    /// sets the company the address belongs to
+    pub fn company(self, company: &'i str) -> Self {
+        Self {
+            company: Some(company),
+            ..self
+        }
+    }
+
+    /// sets the phone number of the person or company the address belongs to
+    pub fn phone(self, phone: &'j str) -> Self {
+        Self {
+            phone: Some(phone),
+            ..self
+        }
+    }
+
+    /// sets the email of the person or company the address belongs to
+    pub fn email(self, email: &'k str) -> Self {
+        Self {
+            email: Some(email),
+            ..self
+        }
+    }
+
+    /// sets an internal description of the address
+    pub fn description(self, description: &'l str) -> Self {
+        Self {
+            description: Some(description),
+            ..self
+        }
+    }
+
+    /// sets the country of the address, lob defaults to `US`
+    pub fn country(self, country: CountryCode) -> Self {
+        Self {
+            country: Some(country),
+            ..self
+        }
+    }
+
+    /// sets the metadata attached to the address
+    pub fn metadata(self, metadata: Metadata<'m>) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> CreateAddressRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm> {
+        CreateAddressRequest {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: JsonRequest {
+                name: self.name,
+                address_line_1: self.address_line_1,
+                address_line_2: self.address_line_2,
+                address_city: self.city,
+                address_state: self.state,
+                address_zip: self.zip_code,
+                company: self.company,
+                phone: self.phone,
+                email: self.email,
+                description: self.description,
+                address_country: self.country,
+                metadata: self.metadata,
+            },
+        }
+    }
+}
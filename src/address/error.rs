@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+use crate::{ApiError, client::send::LobResponseError};
+
+/// Errors that can occur while performing a Lob address request.
+#[derive(Debug, Error)]
+pub enum AddressError {
+    /// HTTP or network error from [`reqwest`].
+    #[error("Reqwest: {0}")]
+    Reqwest(
+        #[from]
+        #[source]
+        reqwest::Error,
+    ),
+
+    /// Failed to serialize the json request
+    #[error("Json: {0}")]
+    Serialize(
+        #[from]
+        #[source]
+        serde_json::Error,
+    ),
+
+    /// An Api Error From Lob
+    #[error("Lob: {0}")]
+    Api(
+        #[from]
+        #[source]
+        ApiError,
+    ),
+
+    /// Failed to parse the JSON response.
+    ///
+    /// Contains the parse error and the raw response body.
+    #[error("Json: {0} - {1}")]
+    Json(#[source] serde_json::Error, String),
+}
+
+impl LobResponseError for AddressError {
+    fn json(err: serde_json::Error, src: String) -> Self {
+        Self::Json(err, src)
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod builder;
+mod error;
+mod request;
+mod response;
+
+pub use error::AddressError;
+pub use response::{Address, AddressId, DeletedAddress};
@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+
+use crate::{
+    Address, AddressError, CountryCode, Metadata, client::send::send, constants::APPLICATION_JSON,
+};
+
+pub struct CreateAddressRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm> {
+    pub(crate) client: reqwest::Client,
+    pub(crate) api_key: &'a str,
+    pub(crate) url: Cow<'b, str>,
+    pub(crate) json_request: JsonRequest<'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm>,
+}
+
+#[derive(Serialize)]
+pub struct JsonRequest<'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm> {
+    pub(crate) name: &'c str,
+    #[serde(rename = "address_line1")]
+    pub(crate) address_line_1: &'d str,
+    #[serde(rename = "address_line2", skip_serializing_if = "Option::is_none")]
+    pub(crate) address_line_2: Option<&'e str>,
+    pub(crate) address_city: &'f str,
+    pub(crate) address_state: &'g str,
+    pub(crate) address_zip: &'h str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) company: Option<&'i str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) phone: Option<&'j str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) email: Option<&'k str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'l str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) address_country: Option<CountryCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'m>>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm>
+    CreateAddressRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm>
+{
+    pub async fn send(self) -> Result<Address, AddressError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
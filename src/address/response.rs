@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{Deleted, id::lob_id};
+
+lob_id!(
+    /// The id of an address saved to your lob address book, prefixed with `adr_`
+    ///
+    /// Can be passed as the `to` or `from` of any mail piece builder in this crate.
+    AddressId
+);
+
+/// An address as returned by lob, either as a mail piece's `to` / `from` or from the address book
+#[derive(Clone, Debug, Deserialize)]
+pub struct Address {
+    /// the id of the address
+    pub id: AddressId,
+    /// the description of the address
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the name of the recipient
+    #[serde(default)]
+    pub name: Option<Box<str>>,
+    /// the company of the recipient
+    #[serde(default)]
+    pub company: Option<Box<str>>,
+    /// the phone number of the recipient
+    #[serde(default)]
+    pub phone: Option<Box<str>>,
+    /// the email of the recipient
+    #[serde(default)]
+    pub email: Option<Box<str>>,
+    /// the first address line
+    pub address_line1: Box<str>,
+    /// the second address line
+    #[serde(default)]
+    pub address_line2: Option<Box<str>>,
+    /// the city
+    #[serde(default)]
+    pub address_city: Option<Box<str>>,
+    /// the state
+    #[serde(default)]
+    pub address_state: Option<Box<str>>,
+    /// the zip code
+    #[serde(default)]
+    pub address_zip: Option<Box<str>>,
+    /// the country
+    #[serde(default)]
+    pub address_country: Option<Box<str>>,
+    /// the metadata attached to the address
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// when the address was created
+    #[serde(default)]
+    pub date_created: Option<Box<str>>,
+    /// when the address was last modified
+    #[serde(default)]
+    pub date_modified: Option<Box<str>>,
+}
+
+/// The response from lob's api after deleting an address
+pub type DeletedAddress = Deleted<AddressId>;
@@ -11,6 +11,7 @@ pub(crate) struct WrapperApiError {
 #[error("Lob: {message} : {code} : {status_code}")]
 pub struct ApiError {
     pub message: Box<str>,
+    #[serde(default)]
     pub code: Box<str>,
     pub status_code: u16,
 }
@@ -0,0 +1,120 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    BillingGroup, BillingGroupError, billing_group::request::BillingGroupJson, client::send::send,
+    constants::APPLICATION_JSON,
+};
+
+/// Builder for a create billing group request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateBillingGroupBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateBillingGroupBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the name of the billing group
+    pub fn name<'c, 'd>(self, name: &'c str) -> CreateBillingGroupBuilderWithName<'a, 'b, 'c, 'd> {
+        CreateBillingGroupBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: BillingGroupJson {
+                name: Some(name),
+                description: None,
+            },
+        }
+    }
+}
+
+/// Builder for a create billing group request with a name set.
+pub struct CreateBillingGroupBuilderWithName<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: BillingGroupJson<'c, 'd>,
+}
+
+impl<'a, 'b, 'c, 'd> CreateBillingGroupBuilderWithName<'a, 'b, 'c, 'd> {
+    /// sets the description of the billing group
+    pub fn description(mut self, description: &'d str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sends the create billing group request
+    pub async fn send(self) -> Result<BillingGroup, BillingGroupError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+/// Builder for an update billing group request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct UpdateBillingGroupBuilder<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: BillingGroupJson<'c, 'd>,
+}
+
+impl<'a, 'b, 'c, 'd> UpdateBillingGroupBuilder<'a, 'b, 'c, 'd> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            json_request: BillingGroupJson {
+                name: None,
+                description: None,
+            },
+        }
+    }
+
+    /// changes the name of the billing group
+    pub fn name(mut self, name: &'c str) -> Self {
+        self.json_request.name = Some(name);
+        self
+    }
+
+    /// changes the description of the billing group
+    pub fn description(mut self, description: &'d str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sends the update billing group request
+    pub async fn send(self) -> Result<BillingGroup, BillingGroupError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::BillingGroupError;
+pub use response::{BillingGroup, BillingGroupId};
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(crate) struct BillingGroupJson<'c, 'd> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<&'c str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'d str>,
+}
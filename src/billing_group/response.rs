@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use crate::id::lob_id;
+
+lob_id!(
+    /// The id of a billing group, prefixed with `bg_`
+    BillingGroupId
+);
+
+/// A billing group as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct BillingGroup {
+    /// the id of the billing group
+    pub id: BillingGroupId,
+    /// the name of the billing group
+    pub name: Box<str>,
+    /// the description of the billing group
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// when the billing group was created
+    pub date_created: Box<str>,
+    /// when the billing group was last modified
+    pub date_modified: Box<str>,
+}
@@ -0,0 +1,524 @@
+use std::borrow::Cow;
+
+use crate::{
+    AddressId, BillingGroupId, MailType, Metadata, UseType,
+    create_postcard::request::{Recipient, To},
+};
+
+use super::request::{BookletSize, CreateBookletRequest, JsonRequest};
+
+/// Builder for creating a create booklet request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateBookletBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateBookletBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the idempotency key for the request. Idempotent requests are requests that can be called many times without producing different outcomes.
+    ///
+    /// You can safely retry the same request with the same Idempotency Key and be assured that no duplicate booklets are created even if the API is called multiple times within 24 hours.
+    pub fn idempotency_key<'c>(
+        self,
+        idempotency_key: &'c str,
+    ) -> CreateBookletBuilderWithIdempotencyKey<'a, 'b, 'c> {
+        CreateBookletBuilderWithIdempotencyKey {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key,
+        }
+    }
+}
+
+/// Builder for a create booklet request with an idempotency key set.
+pub struct CreateBookletBuilderWithIdempotencyKey<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+}
+
+impl<'a, 'b, 'c> CreateBookletBuilderWithIdempotencyKey<'a, 'b, 'c> {
+    /// sets the name for the address to which the booklet is being sent
+    pub fn name<'d>(self, name: &'d str) -> CreateBookletBuilderWithName<'a, 'b, 'c, 'd> {
+        CreateBookletBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name,
+        }
+    }
+
+    /// sends the booklet to an address saved to your lob address book
+    pub fn to<'d, 'e, 'f, 'g, 'h, 'i>(
+        self,
+        to: &'d AddressId,
+    ) -> CreateBookletBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreateBookletBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Id(to),
+        }
+    }
+}
+
+/// Builder for a create booklet request with a name set.
+pub struct CreateBookletBuilderWithName<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+}
+
+impl<'a, 'b, 'c, 'd> CreateBookletBuilderWithName<'a, 'b, 'c, 'd> {
+    /// sets the first address line for the address to which the booklet is being sent
+    pub fn address_line_1<'e, 'f>(
+        self,
+        address_line_1: &'e str,
+    ) -> CreateBookletBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+        CreateBookletBuilderWithAddress {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1,
+            address_line_2: None,
+        }
+    }
+}
+
+/// Builder for a create booklet request with addresses set.
+pub struct CreateBookletBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> CreateBookletBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+    /// sets the second address line for the address to which the booklet is being sent
+    pub fn address_line_2(self, address_line_2: &'f str) -> Self {
+        Self {
+            address_line_2: Some(address_line_2),
+            ..self
+        }
+    }
+
+    /// sets the city for the address to which the booklet is being sent
+    pub fn city<'g>(
+        self,
+        city: &'g str,
+    ) -> CreateBookletBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        CreateBookletBuilderWithCity {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a city set.
+pub struct CreateBookletBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+    city: &'g str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> CreateBookletBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    /// sets the state for the address to which the booklet is being sent
+    pub fn state<'h>(
+        self,
+        state: &'h str,
+    ) -> CreateBookletBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        CreateBookletBuilderWithState {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a state set.
+pub struct CreateBookletBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+    city: &'g str,
+    state: &'h str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> CreateBookletBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    /// sets the zip code for the address to which the booklet is being sent
+    pub fn zip_code<'i>(
+        self,
+        zip_code: &'i str,
+    ) -> CreateBookletBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreateBookletBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: self.address_line_1,
+                address_line_2: self.address_line_2,
+                address_city: self.city,
+                address_state: self.state,
+                address_zip: zip_code,
+                address_country: None,
+            }),
+        }
+    }
+}
+
+/// Builder for a create booklet request with a zip code set.
+pub struct CreateBookletBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+    CreateBookletBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+{
+    /// sets the return address of the booklet, as the id of an address saved to your lob address book (`adr_...`)
+    pub fn from<'j>(
+        self,
+        from: &'j AddressId,
+    ) -> CreateBookletBuilderWithFrom<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+        CreateBookletBuilderWithFrom {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a return address set.
+pub struct CreateBookletBuilderWithFrom<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+    CreateBookletBuilderWithFrom<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+{
+    /// sets the artwork of the booklet being sent.
+    ///
+    /// The url of a PDF holding every page of your booklet, cover included.
+    ///
+    /// ## Notes:
+    ///
+    /// The PDF must be sized at 8.375"x5.375" at 300 DPI, and hold between [`BOOKLET_MIN_PAGES`](crate::BOOKLET_MIN_PAGES)
+    /// and [`BOOKLET_MAX_PAGES`](crate::BOOKLET_MAX_PAGES) pages in a multiple of 4, which lob checks when rendering the booklet.
+    pub fn file<'k>(
+        self,
+        file: &'k str,
+    ) -> CreateBookletBuilderWithFile<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateBookletBuilderWithFile {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            file,
+        }
+    }
+}
+
+/// Builder for a create booklet request with the artwork file set.
+pub struct CreateBookletBuilderWithFile<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    file: &'k str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateBookletBuilderWithFile<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the "usage type" for the booklet being sent.
+    ///
+    /// The use type for each mailpiece. Can be one of marketing, operational. For more information on use_type, see lob's Help Center article.
+    pub fn use_type(
+        self,
+        use_type: UseType,
+    ) -> CreateBookletBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateBookletBuilderWithUseType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            file: self.file,
+            use_type,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a use type set.
+pub struct CreateBookletBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    file: &'k str,
+    use_type: UseType,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateBookletBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the "size" for the booklet being sent.
+    pub fn size(
+        self,
+        size: BookletSize,
+    ) -> CreateBookletBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateBookletBuilderWithSize {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            file: self.file,
+            use_type: self.use_type,
+            size,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a booklet size set.
+pub struct CreateBookletBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    file: &'k str,
+    use_type: UseType,
+    size: BookletSize,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateBookletBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the "mail type" for the booklet being sent.
+    pub fn mail_type(
+        self,
+        mail_type: MailType,
+    ) -> CreateBookletBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateBookletBuilderWithMailType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            file: self.file,
+            use_type: self.use_type,
+            size: self.size,
+            mail_type,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a mail type set.
+pub struct CreateBookletBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    file: &'k str,
+    use_type: UseType,
+    size: BookletSize,
+    mail_type: MailType,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateBookletBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the description of the booklet being sent.
+    pub fn description<'l, 'm, 'n, 'o>(
+        self,
+        description: &'l str,
+    ) -> CreateBookletBuilderWithDescription<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+    > {
+        CreateBookletBuilderWithDescription {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            file: self.file,
+            use_type: self.use_type,
+            size: self.size,
+            mail_type: self.mail_type,
+            description,
+            metadata: None,
+            send_date: None,
+            billing_group_id: None,
+        }
+    }
+}
+
+/// Builder for a create booklet request with a description set.
+pub struct CreateBookletBuilderWithDescription<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    file: &'k str,
+    use_type: UseType,
+    size: BookletSize,
+    mail_type: MailType,
+    description: &'l str,
+    metadata: Option<Metadata<'m>>,
+    send_date: Option<&'n str>,
+    billing_group_id: Option<&'o BillingGroupId>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o>
+    CreateBookletBuilderWithDescription<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o>
+{
+    /// sets the metadata attached to the booklet being sent.
+    pub fn metadata(self, metadata: Metadata<'m>) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    /// sets the date the booklet will be sent.
+    ///
+    /// A timestamp in ISO 8601 format which specifies a date after the current time and up to 180 days in the future to send the booklet off for production. Until the send date, the booklet can be cancelled.
+    pub fn send_date(self, send_date: &'n str) -> Self {
+        Self {
+            send_date: Some(send_date),
+            ..self
+        }
+    }
+
+    /// bills the booklet to one of your billing groups
+    pub fn billing_group_id(self, billing_group_id: &'o BillingGroupId) -> Self {
+        Self {
+            billing_group_id: Some(billing_group_id),
+            ..self
+        }
+    }
+
+    pub fn build(
+        self,
+    ) -> CreateBookletRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o> {
+        CreateBookletRequest {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            json_request: JsonRequest {
+                to: self.to,
+                from: self.from,
+                file: self.file,
+                use_type: self.use_type,
+                size: self.size,
+                mail_type: self.mail_type,
+                description: self.description,
+                metadata: self.metadata,
+                send_date: self.send_date,
+                billing_group_id: self.billing_group_id,
+            },
+        }
+    }
+}
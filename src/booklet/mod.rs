@@ -0,0 +1,8 @@
+pub(crate) mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::BookletError;
+pub use request::{BOOKLET_MAX_PAGES, BOOKLET_MIN_PAGES, BookletSize};
+pub use response::{Booklet, BookletId};
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AddressId, BillingGroupId, Booklet, BookletError, MailType, Metadata, UseType,
+    client::send::send,
+    constants::{APPLICATION_JSON, IDEMPOTENCY_KEY},
+    create_postcard::request::Recipient,
+};
+
+/// The fewest pages, cover included, lob will print in a booklet
+pub const BOOKLET_MIN_PAGES: u16 = 8;
+
+/// The most pages, cover included, lob will print in a booklet
+pub const BOOKLET_MAX_PAGES: u16 = 32;
+
+pub struct CreateBookletRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o> {
+    pub(crate) client: reqwest::Client,
+    pub(crate) api_key: &'a str,
+    pub(crate) url: Cow<'b, str>,
+    pub(crate) idempotency_key: &'c str,
+    pub(crate) json_request: JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o>,
+}
+
+#[derive(Serialize)]
+pub struct JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    pub(crate) from: &'j AddressId,
+    pub(crate) file: &'k str,
+    pub(crate) size: BookletSize,
+    pub(crate) mail_type: MailType,
+    pub(crate) description: &'l str,
+    pub(crate) use_type: UseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'m>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'n str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'o BillingGroupId>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Specifies the size of the booklet. Booklets are saddle stitched.
+pub enum BookletSize {
+    #[serde(rename = "8.375x5.375")]
+    EightAndThreeEighthsByFiveAndThreeEighths,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o>
+    CreateBookletRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o>
+{
+    pub async fn send(self) -> Result<Booklet, BookletError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .header(IDEMPOTENCY_KEY, self.idempotency_key)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{Address, BookletSize, MailType, TrackingEvent, UseType, id::lob_id};
+
+lob_id!(
+    /// The id of a booklet, prefixed with `bkl_`
+    BookletId
+);
+
+/// A booklet as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Booklet {
+    /// the id of the booklet
+    pub id: BookletId,
+    /// the description of the booklet
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the recipient of the booklet
+    pub to: Address,
+    /// the sender of the booklet
+    pub from: Address,
+    /// a signed link to the rendered booklet
+    #[serde(default)]
+    pub url: Option<Box<str>>,
+    /// the size of the booklet
+    pub size: BookletSize,
+    /// the postage type of the booklet
+    pub mail_type: MailType,
+    /// the use type of the booklet
+    #[serde(default)]
+    pub use_type: Option<UseType>,
+    /// the metadata attached to the booklet
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// the send date
+    pub send_date: Box<str>,
+    /// the expected delivery date
+    #[serde(default)]
+    pub expected_delivery_date: Option<Box<str>>,
+    /// when the booklet was created
+    pub date_created: Box<str>,
+    /// when the booklet was last modified
+    pub date_modified: Box<str>,
+    /// the usps scans of the booklet so far, oldest first
+    #[serde(default)]
+    pub tracking_events: Vec<TrackingEvent>,
+    /// whether the booklet has been cancelled
+    #[serde(default)]
+    pub deleted: bool,
+}
@@ -0,0 +1,7 @@
+pub(crate) mod request;
+mod response;
+
+pub use request::{BUCKSLIP_ORDER_MIN_QUANTITY, BuckslipSize};
+pub use response::{
+    Buckslip, BuckslipError, BuckslipId, BuckslipOrder, BuckslipOrderId, BuckslipOrderQuantity,
+};
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The fewest buckslips lob prints in a single order
+pub const BUCKSLIP_ORDER_MIN_QUANTITY: u32 = 5_000;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Specifies the size of the buckslip.
+pub enum BuckslipSize {
+    #[serde(rename = "8.75x3.75")]
+    EightAndThreeQuartersByThreeAndThreeQuarters,
+}
@@ -0,0 +1,20 @@
+use crate::{BUCKSLIP_ORDER_MIN_QUANTITY, BuckslipSize, print_order::print_insert};
+
+print_insert! {
+    noun: "buckslip",
+    path: "buckslips",
+    size: BuckslipSize,
+    min_order_quantity: BUCKSLIP_ORDER_MIN_QUANTITY,
+    /// A buckslip as returned by lob's api
+    insert: Buckslip,
+    /// The id of a buckslip, prefixed with `bck_`
+    id: BuckslipId,
+    /// A print order for a buckslip as returned by lob's api
+    order: BuckslipOrder { buckslip_id },
+    /// The id of a buckslip print order, prefixed with `bo_`
+    order_id: BuckslipOrderId,
+    /// How many buckslips to print in one order, at least [`BUCKSLIP_ORDER_MIN_QUANTITY`]
+    quantity: BuckslipOrderQuantity,
+    /// Errors that can occur while performing a Lob buckslip request.
+    error: BuckslipError,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ApiError, WrapperApiError};
+
+#[derive(Serialize)]
+pub(crate) struct BulkRequest<'a, T> {
+    pub(crate) addresses: &'a [T],
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BulkResponse<T> {
+    pub(crate) addresses: Vec<BulkResult<T>>,
+}
+
+/// lob reports a bad row as an error envelope in place of the verification
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum BulkResult<T> {
+    Err(WrapperApiError),
+    Ok(Box<T>),
+}
+
+impl<T> From<BulkResult<T>> for Result<T, ApiError> {
+    fn from(result: BulkResult<T>) -> Self {
+        match result {
+            BulkResult::Err(wrapper_api_error) => Err(wrapper_api_error.error),
+            BulkResult::Ok(ok) => Ok(*ok),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    BillingGroupId, Campaign, CampaignError, Metadata, ScheduleType, UseType,
+    campaign::request::{CreateCampaignJson, UpdateCampaignJson},
+    client::send::send,
+    constants::APPLICATION_JSON,
+};
+
+/// Builder for a create campaign request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateCampaignBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateCampaignBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the name of the campaign
+    pub fn name<'c>(self, name: &'c str) -> CreateCampaignBuilderWithName<'a, 'b, 'c> {
+        CreateCampaignBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name,
+        }
+    }
+}
+
+/// Builder for a create campaign request with a name set.
+pub struct CreateCampaignBuilderWithName<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+}
+
+impl<'a, 'b, 'c> CreateCampaignBuilderWithName<'a, 'b, 'c> {
+    /// sets the "usage type" for every mail piece in the campaign.
+    pub fn use_type(self, use_type: UseType) -> CreateCampaignBuilderWithUseType<'a, 'b, 'c> {
+        CreateCampaignBuilderWithUseType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            use_type,
+        }
+    }
+}
+
+/// Builder for a create campaign request with a use type set.
+pub struct CreateCampaignBuilderWithUseType<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    use_type: UseType,
+}
+
+impl<'a, 'b, 'c> CreateCampaignBuilderWithUseType<'a, 'b, 'c> {
+    /// schedules the campaign to be mailed immediately after [`LobClient::send_campaign`](crate::LobClient::send_campaign)
+    pub fn immediate<'d, 'e, 'f, 'g, 'h>(
+        self,
+    ) -> CreateCampaignBuilderWithSchedule<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        self.schedule(ScheduleType::Immediate, None)
+    }
+
+    /// schedules the campaign so lob aims to deliver it by `target_delivery_date`, in ISO 8601 format
+    pub fn target_delivery_date<'d, 'e, 'f, 'g, 'h>(
+        self,
+        target_delivery_date: &'d str,
+    ) -> CreateCampaignBuilderWithSchedule<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        self.schedule(ScheduleType::TargetDeliveryDate, Some(target_delivery_date))
+    }
+
+    fn schedule<'d, 'e, 'f, 'g, 'h>(
+        self,
+        schedule_type: ScheduleType,
+        target_delivery_date: Option<&'d str>,
+    ) -> CreateCampaignBuilderWithSchedule<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        CreateCampaignBuilderWithSchedule {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: CreateCampaignJson {
+                name: self.name,
+                use_type: self.use_type,
+                schedule_type,
+                target_delivery_date,
+                description: None,
+                send_date: None,
+                cancel_window_campaign_minutes: None,
+                metadata: None,
+                billing_group_id: None,
+            },
+        }
+    }
+}
+
+/// Builder for a create campaign request with a schedule set.
+pub struct CreateCampaignBuilderWithSchedule<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: CreateCampaignJson<'c, 'd, 'e, 'f, 'g, 'h>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+    CreateCampaignBuilderWithSchedule<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+{
+    /// sets the description of the campaign
+    pub fn description(mut self, description: &'e str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sets the date the campaign will be sent, in ISO 8601 format
+    pub fn send_date(mut self, send_date: &'f str) -> Self {
+        self.json_request.send_date = Some(send_date);
+        self
+    }
+
+    /// sets how many minutes the campaign can be cancelled for after it is sent
+    pub fn cancel_window_campaign_minutes(mut self, minutes: u32) -> Self {
+        self.json_request.cancel_window_campaign_minutes = Some(minutes);
+        self
+    }
+
+    /// sets the metadata attached to the campaign
+    pub fn metadata(mut self, metadata: Metadata<'g>) -> Self {
+        self.json_request.metadata = Some(metadata);
+        self
+    }
+
+    /// bills the campaign to one of your billing groups
+    pub fn billing_group_id(mut self, billing_group_id: &'h BillingGroupId) -> Self {
+        self.json_request.billing_group_id = Some(billing_group_id);
+        self
+    }
+
+    /// sends the create campaign request, the campaign starts as a draft
+    pub async fn send(self) -> Result<Campaign, CampaignError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+/// Builder for an update campaign request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct UpdateCampaignBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: UpdateCampaignJson<'c, 'd, 'e, 'f, 'g>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> UpdateCampaignBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            json_request: UpdateCampaignJson {
+                name: None,
+                schedule_type: None,
+                target_delivery_date: None,
+                description: None,
+                send_date: None,
+                cancel_window_campaign_minutes: None,
+                metadata: None,
+                is_draft: None,
+            },
+        }
+    }
+
+    /// changes the name of the campaign
+    pub fn name(mut self, name: &'c str) -> Self {
+        self.json_request.name = Some(name);
+        self
+    }
+
+    /// reschedules the campaign to be mailed immediately after [`LobClient::send_campaign`](crate::LobClient::send_campaign)
+    pub fn immediate(mut self) -> Self {
+        self.json_request.schedule_type = Some(ScheduleType::Immediate);
+        self.json_request.target_delivery_date = None;
+        self
+    }
+
+    /// reschedules the campaign so lob aims to deliver it by `target_delivery_date`
+    pub fn target_delivery_date(mut self, target_delivery_date: &'d str) -> Self {
+        self.json_request.schedule_type = Some(ScheduleType::TargetDeliveryDate);
+        self.json_request.target_delivery_date = Some(target_delivery_date);
+        self
+    }
+
+    /// changes the description of the campaign
+    pub fn description(mut self, description: &'e str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// changes the date the campaign will be sent
+    pub fn send_date(mut self, send_date: &'f str) -> Self {
+        self.json_request.send_date = Some(send_date);
+        self
+    }
+
+    /// changes how many minutes the campaign can be cancelled for after it is sent
+    pub fn cancel_window_campaign_minutes(mut self, minutes: u32) -> Self {
+        self.json_request.cancel_window_campaign_minutes = Some(minutes);
+        self
+    }
+
+    /// replaces the metadata attached to the campaign
+    pub fn metadata(mut self, metadata: Metadata<'g>) -> Self {
+        self.json_request.metadata = Some(metadata);
+        self
+    }
+
+    /// changes whether the campaign is a draft
+    pub fn draft(mut self, is_draft: bool) -> Self {
+        self.json_request.is_draft = Some(is_draft);
+        self
+    }
+
+    /// sends the update campaign request
+    pub async fn send(self) -> Result<Campaign, CampaignError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .request(Method::PATCH, self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+use crate::{BillingGroupId, Metadata, ScheduleType, UseType};
+
+#[derive(Serialize)]
+pub(crate) struct CreateCampaignJson<'c, 'd, 'e, 'f, 'g, 'h> {
+    pub(crate) name: &'c str,
+    pub(crate) use_type: UseType,
+    pub(crate) schedule_type: ScheduleType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) target_delivery_date: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'e str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'f str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cancel_window_campaign_minutes: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'g>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'h BillingGroupId>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UpdateCampaignJson<'c, 'd, 'e, 'f, 'g> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<&'c str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) schedule_type: Option<ScheduleType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) target_delivery_date: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'e str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'f str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cancel_window_campaign_minutes: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'g>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_draft: Option<bool>,
+}
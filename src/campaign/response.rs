@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BillingGroupId, UseType, id::lob_id};
+
+lob_id!(
+    /// The id of a campaign, prefixed with `cmp_`
+    CampaignId
+);
+
+/// A campaign as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Campaign {
+    /// the id of the campaign
+    pub id: CampaignId,
+    /// the name of the campaign
+    pub name: Box<str>,
+    /// the description of the campaign
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// how the campaign is scheduled
+    pub schedule_type: ScheduleType,
+    /// the date lob aims to deliver the campaign by, for [`ScheduleType::TargetDeliveryDate`]
+    #[serde(default)]
+    pub target_delivery_date: Option<Box<str>>,
+    /// the date the campaign will be sent
+    #[serde(default)]
+    pub send_date: Option<Box<str>>,
+    /// how many minutes the campaign can be cancelled for after it is sent
+    #[serde(default)]
+    pub cancel_window_campaign_minutes: Option<u32>,
+    /// the use type of the campaign's mail
+    pub use_type: UseType,
+    /// whether the campaign is still a draft
+    #[serde(default)]
+    pub is_draft: bool,
+    /// the status of the campaign, e.g. `draft` or `sent`
+    #[serde(default)]
+    pub status: Option<Box<str>>,
+    /// the billing group the campaign is billed to
+    #[serde(default)]
+    pub billing_group_id: Option<BillingGroupId>,
+    /// the metadata attached to the campaign
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// when the campaign was created
+    pub date_created: Box<str>,
+    /// when the campaign was last modified
+    pub date_modified: Box<str>,
+}
+
+/// How a campaign is scheduled
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleType {
+    /// The campaign is mailed immediately after [`LobClient::send_campaign`](crate::LobClient::send_campaign).
+    Immediate,
+    /// Lob schedules the campaign to arrive by its target delivery date.
+    TargetDeliveryDate,
+}
@@ -0,0 +1,5 @@
+pub(crate) mod request;
+mod response;
+
+pub use request::{CARD_ORDER_MIN_QUANTITY, CardSize};
+pub use response::{Card, CardError, CardId, CardOrder, CardOrderId, CardOrderQuantity};
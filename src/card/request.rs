@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The fewest cards lob prints in a single order
+pub const CARD_ORDER_MIN_QUANTITY: u32 = 10_000;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Specifies the size of the card.
+pub enum CardSize {
+    #[serde(rename = "2.125x3.375")]
+    TwoAndOneEighthByThreeAndThreeEighths,
+}
@@ -0,0 +1,20 @@
+use crate::{CARD_ORDER_MIN_QUANTITY, CardSize, print_order::print_insert};
+
+print_insert! {
+    noun: "card",
+    path: "cards",
+    size: CardSize,
+    min_order_quantity: CARD_ORDER_MIN_QUANTITY,
+    /// A card as returned by lob's api
+    insert: Card,
+    /// The id of a card, prefixed with `card_`
+    id: CardId,
+    /// A print order for a card as returned by lob's api
+    order: CardOrder { card_id },
+    /// The id of a card print order, prefixed with `co_`
+    order_id: CardOrderId,
+    /// How many cards to print in one order, at least [`CARD_ORDER_MIN_QUANTITY`]
+    quantity: CardOrderQuantity,
+    /// Errors that can occur while performing a Lob card request.
+    error: CardError,
+}
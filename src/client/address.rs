@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    Address, AddressError, AddressId, DeletedAddress, LobClient,
+    address::builder::CreateAddressBuilder,
+    client::send::send,
+    constants::{DEFAULT_ADDRESSES_URL, DEFAULT_BASE_URL},
+    list::ListBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn addresses_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_ADDRESSES_URL),
+            false => Cow::Owned(build_url(self.base_url, "addresses")),
+        }
+    }
+
+    /// create an address book entry request builder
+    pub fn create_address(&self) -> CreateAddressBuilder<'a, 'b> {
+        CreateAddressBuilder::new(self.client.clone(), self.api_key, self.addresses_url())
+    }
+
+    /// retrieve an address saved to the address book by its id
+    pub async fn retrieve_address(&self, id: &AddressId) -> Result<Address, AddressError> {
+        let url = build_url(self.base_url, &format!("addresses/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list the addresses saved to the address book, most recently created first
+    pub fn list_addresses<'c>(&self) -> ListBuilder<'a, 'b, 'c, Address, AddressError> {
+        ListBuilder::new(self.clone(), self.addresses_url())
+    }
+
+    /// delete an address from the address book
+    pub async fn delete_address(&self, id: &AddressId) -> Result<DeletedAddress, AddressError> {
+        let url = build_url(self.base_url, &format!("addresses/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
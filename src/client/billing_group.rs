@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    BillingGroup, BillingGroupError, BillingGroupId, LobClient,
+    billing_group::builder::{CreateBillingGroupBuilder, UpdateBillingGroupBuilder},
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_BILLING_GROUPS_URL},
+    list::ListBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn billing_groups_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_BILLING_GROUPS_URL),
+            false => Cow::Owned(build_url(self.base_url, "billing_groups")),
+        }
+    }
+
+    /// create a billing group request builder
+    pub fn create_billing_group(&self) -> CreateBillingGroupBuilder<'a, 'b> {
+        CreateBillingGroupBuilder::new(self.client.clone(), self.api_key, self.billing_groups_url())
+    }
+
+    /// retrieve a billing group by its id
+    pub async fn retrieve_billing_group(
+        &self,
+        id: &BillingGroupId,
+    ) -> Result<BillingGroup, BillingGroupError> {
+        let url = build_url(self.base_url, &format!("billing_groups/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list billing groups, most recently created first
+    pub fn list_billing_groups<'c>(
+        &self,
+    ) -> ListBuilder<'a, 'b, 'c, BillingGroup, BillingGroupError> {
+        ListBuilder::new(self.clone(), self.billing_groups_url())
+    }
+
+    /// create a request builder updating a billing group's name or description
+    pub fn update_billing_group<'c, 'd>(
+        &self,
+        id: &BillingGroupId,
+    ) -> UpdateBillingGroupBuilder<'a, 'b, 'c, 'd> {
+        let url = build_url(self.base_url, &format!("billing_groups/{id}"));
+
+        UpdateBillingGroupBuilder::new(self.client.clone(), self.api_key, Cow::Owned(url))
+    }
+}
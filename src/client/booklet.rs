@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    Booklet, BookletError, BookletId, Deleted, LobClient, TrackingEvent,
+    booklet::builder::CreateBookletBuilder,
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_BOOKLETS_URL},
+    list::ListBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn booklets_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_BOOKLETS_URL),
+            false => Cow::Owned(build_url(self.base_url, "booklets")),
+        }
+    }
+
+    /// create a booklet request builder
+    pub fn create_booklet(&self) -> CreateBookletBuilder<'a, 'b> {
+        CreateBookletBuilder::new(self.client.clone(), self.api_key, self.booklets_url())
+    }
+
+    /// retrieve a previously created booklet by its id
+    pub async fn retrieve_booklet(&self, id: &BookletId) -> Result<Booklet, BookletError> {
+        let url = build_url(self.base_url, &format!("booklets/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// the full usps tracking history of a booklet, oldest first
+    ///
+    /// Lob embeds tracking events in the booklet rather than listing them, so this retrieves it.
+    pub async fn booklet_tracking_events(
+        &self,
+        id: &BookletId,
+    ) -> Result<Vec<TrackingEvent>, BookletError> {
+        Ok(self.retrieve_booklet(id).await?.tracking_events)
+    }
+
+    /// list booklets, most recently created first
+    pub fn list_booklets<'c>(&self) -> ListBuilder<'a, 'b, 'c, Booklet, BookletError> {
+        ListBuilder::new(self.clone(), self.booklets_url())
+    }
+
+    /// cancel a booklet before its send date
+    pub async fn cancel_booklet(&self, id: &BookletId) -> Result<Deleted<BookletId>, BookletError> {
+        let url = build_url(self.base_url, &format!("booklets/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
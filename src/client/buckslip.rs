@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use crate::{
+    Buckslip, BuckslipError, BuckslipId, BuckslipOrder, BuckslipOrderQuantity, Deleted, LobClient,
+    constants::{DEFAULT_BASE_URL, DEFAULT_BUCKSLIPS_URL},
+    list::ListBuilder,
+    print_order::builder::{CreatePrintInsertBuilder, UpdatePrintInsertBuilder},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn buckslips_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_BUCKSLIPS_URL),
+            false => Cow::Owned(build_url(self.base_url, "buckslips")),
+        }
+    }
+
+    /// create a buckslip request builder
+    pub fn create_buckslip(&self) -> CreatePrintInsertBuilder<'a, 'b, Buckslip> {
+        self.create_print_insert(self.buckslips_url())
+    }
+
+    /// retrieve a buckslip by its id
+    pub async fn retrieve_buckslip(&self, id: &BuckslipId) -> Result<Buckslip, BuckslipError> {
+        self.retrieve_print_insert::<Buckslip>(id).await
+    }
+
+    /// list buckslips, most recently created first
+    pub fn list_buckslips<'c>(&self) -> ListBuilder<'a, 'b, 'c, Buckslip, BuckslipError> {
+        ListBuilder::new(self.clone(), self.buckslips_url())
+    }
+
+    /// create a request builder updating a buckslip's description or reordering
+    pub fn update_buckslip<'c>(
+        &self,
+        id: &BuckslipId,
+    ) -> UpdatePrintInsertBuilder<'a, 'b, 'c, Buckslip> {
+        self.update_print_insert(id)
+    }
+
+    /// delete a buckslip
+    pub async fn delete_buckslip(
+        &self,
+        id: &BuckslipId,
+    ) -> Result<Deleted<BuckslipId>, BuckslipError> {
+        self.delete_print_insert::<Buckslip>(id).await
+    }
+
+    /// order `quantity` prints of a buckslip
+    pub async fn create_buckslip_order(
+        &self,
+        id: &BuckslipId,
+        quantity: BuckslipOrderQuantity,
+    ) -> Result<BuckslipOrder, BuckslipError> {
+        self.create_print_order(id, quantity).await
+    }
+
+    /// list the print orders of a buckslip, most recently created first
+    pub fn list_buckslip_orders<'c>(
+        &self,
+        id: &BuckslipId,
+    ) -> ListBuilder<'a, 'b, 'c, BuckslipOrder, BuckslipError> {
+        self.list_print_orders::<Buckslip>(id)
+    }
+}
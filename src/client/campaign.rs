@@ -0,0 +1,97 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    Campaign, CampaignError, CampaignId, Creative, CreativeError, CreativeId, Deleted, LobClient,
+    campaign::builder::{CreateCampaignBuilder, UpdateCampaignBuilder},
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_CAMPAIGNS_URL, DEFAULT_CREATIVES_URL},
+    creative::builder::{CreateCreativeBuilder, UpdateCreativeBuilder},
+    list::ListBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn campaigns_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_CAMPAIGNS_URL),
+            false => Cow::Owned(build_url(self.base_url, "campaigns")),
+        }
+    }
+
+    /// create a campaign request builder
+    pub fn create_campaign(&self) -> CreateCampaignBuilder<'a, 'b> {
+        CreateCampaignBuilder::new(self.client.clone(), self.api_key, self.campaigns_url())
+    }
+
+    /// retrieve a campaign by its id
+    pub async fn retrieve_campaign(&self, id: &CampaignId) -> Result<Campaign, CampaignError> {
+        let url = build_url(self.base_url, &format!("campaigns/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list campaigns, most recently created first
+    pub fn list_campaigns<'c>(&self) -> ListBuilder<'a, 'b, 'c, Campaign, CampaignError> {
+        ListBuilder::new(self.clone(), self.campaigns_url())
+    }
+
+    /// create a request builder updating a campaign's name, schedule or metadata
+    pub fn update_campaign<'c, 'd, 'e, 'f, 'g>(
+        &self,
+        id: &CampaignId,
+    ) -> UpdateCampaignBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        let url = build_url(self.base_url, &format!("campaigns/{id}"));
+
+        UpdateCampaignBuilder::new(self.client.clone(), self.api_key, Cow::Owned(url))
+    }
+
+    /// delete a campaign that has not been sent yet
+    pub async fn delete_campaign(
+        &self,
+        id: &CampaignId,
+    ) -> Result<Deleted<CampaignId>, CampaignError> {
+        let url = build_url(self.base_url, &format!("campaigns/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+
+    /// send a campaign, creating a mail piece for every member of its audience
+    pub async fn send_campaign(&self, id: &CampaignId) -> Result<Campaign, CampaignError> {
+        let url = build_url(self.base_url, &format!("campaigns/{id}/send"));
+
+        send(self.request(Method::POST, url.as_str())).await
+    }
+
+    /// create a request builder attaching a creative to `campaign`
+    pub fn create_creative<'c>(
+        &self,
+        campaign: &'c CampaignId,
+    ) -> CreateCreativeBuilder<'a, 'b, 'c> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_CREATIVES_URL),
+            false => Cow::Owned(build_url(self.base_url, "creatives")),
+        };
+
+        CreateCreativeBuilder::new(self.client.clone(), self.api_key, url, campaign)
+    }
+
+    /// retrieve a creative by its id
+    pub async fn retrieve_creative(&self, id: &CreativeId) -> Result<Creative, CreativeError> {
+        let url = build_url(self.base_url, &format!("creatives/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// create a request builder updating a creative's description or metadata
+    pub fn update_creative<'c, 'd>(
+        &self,
+        id: &CreativeId,
+    ) -> UpdateCreativeBuilder<'a, 'b, 'c, 'd> {
+        let url = build_url(self.base_url, &format!("creatives/{id}"));
+
+        UpdateCreativeBuilder::new(self.client.clone(), self.api_key, Cow::Owned(url))
+    }
+}
@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+
+use crate::{
+    Card, CardError, CardId, CardOrder, CardOrderQuantity, Deleted, LobClient,
+    constants::{DEFAULT_BASE_URL, DEFAULT_CARDS_URL},
+    list::ListBuilder,
+    print_order::builder::{CreatePrintInsertBuilder, UpdatePrintInsertBuilder},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn cards_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_CARDS_URL),
+            false => Cow::Owned(build_url(self.base_url, "cards")),
+        }
+    }
+
+    /// create a card request builder
+    pub fn create_card(&self) -> CreatePrintInsertBuilder<'a, 'b, Card> {
+        self.create_print_insert(self.cards_url())
+    }
+
+    /// retrieve a card by its id
+    pub async fn retrieve_card(&self, id: &CardId) -> Result<Card, CardError> {
+        self.retrieve_print_insert::<Card>(id).await
+    }
+
+    /// list cards, most recently created first
+    pub fn list_cards<'c>(&self) -> ListBuilder<'a, 'b, 'c, Card, CardError> {
+        ListBuilder::new(self.clone(), self.cards_url())
+    }
+
+    /// create a request builder updating a card's description or reordering
+    pub fn update_card<'c>(&self, id: &CardId) -> UpdatePrintInsertBuilder<'a, 'b, 'c, Card> {
+        self.update_print_insert(id)
+    }
+
+    /// delete a card
+    pub async fn delete_card(&self, id: &CardId) -> Result<Deleted<CardId>, CardError> {
+        self.delete_print_insert::<Card>(id).await
+    }
+
+    /// order `quantity` prints of a card
+    pub async fn create_card_order(
+        &self,
+        id: &CardId,
+        quantity: CardOrderQuantity,
+    ) -> Result<CardOrder, CardError> {
+        self.create_print_order(id, quantity).await
+    }
+
+    /// list the print orders of a card, most recently created first
+    pub fn list_card_orders<'c>(
+        &self,
+        id: &CardId,
+    ) -> ListBuilder<'a, 'b, 'c, CardOrder, CardError> {
+        self.list_print_orders::<Card>(id)
+    }
+}
@@ -1,21 +1,246 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::{HashSet, VecDeque},
+    pin::pin,
+    time::Duration,
+};
+
+use futures_util::{Stream, StreamExt, stream};
+use reqwest::{Method, Response, StatusCode};
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    time::{Instant, sleep},
+};
 
 use crate::{
-    LobClient,
+    LobClient, POSTCARD_POLL_INTERVAL, POSTCARD_POLL_MAX_INTERVAL, POSTCARD_RENDER_POLL_INTERVAL,
+    Postcard, PostcardError, PostcardId, PostcardStatusUpdate, RenderedFile, TrackingEvent,
+    TrackingEventId, TrackingEventName,
+    client::send::send,
     constants::{DEFAULT_BASE_URL, DEFAULT_POSTCARDS_URL},
     create_postcard::builder::CreatePostcardBuilder,
+    list::ListBuilder,
 };
 
+use super::build_url::build_url;
+
 impl<'a, 'b> LobClient<'a, 'b> {
+    fn postcards_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_POSTCARDS_URL),
+            false => Cow::Owned(build_url(self.base_url, "postcards")),
+        }
+    }
+
     /// create a postcard request builder
     pub fn create_postcard(&self) -> CreatePostcardBuilder<'a, 'b> {
-        use super::build_url::build_url;
+        CreatePostcardBuilder::new(self.client.clone(), self.api_key, self.postcards_url())
+    }
 
-        let url = match self.base_url == DEFAULT_BASE_URL {
-            true => Cow::Borrowed(DEFAULT_POSTCARDS_URL),
-            false => Cow::Owned(build_url(self.base_url, "postcards")),
+    /// retrieve a previously created postcard by its id
+    pub async fn retrieve_postcard(&self, id: &PostcardId) -> Result<Postcard, PostcardError> {
+        let url = build_url(self.base_url, &format!("postcards/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list postcards, most recently created first
+    pub fn list_postcards<'c>(&self) -> ListBuilder<'a, 'b, 'c, Postcard, PostcardError> {
+        ListBuilder::new(self.clone(), self.postcards_url())
+    }
+
+    /// the full usps tracking history of a postcard, oldest first
+    ///
+    /// Lob embeds tracking events in the postcard rather than listing them, so this retrieves it.
+    pub async fn postcard_tracking_events(
+        &self,
+        id: &PostcardId,
+    ) -> Result<Vec<TrackingEvent>, PostcardError> {
+        Ok(self.retrieve_postcard(id).await?.tracking_events)
+    }
+
+    /// poll a postcard until one of its tracking events [reaches](TrackingEventName::reaches) `target`, yielding each new tracking event on the way
+    ///
+    /// Checks start [`POSTCARD_POLL_INTERVAL`] apart and back off up to [`POSTCARD_POLL_MAX_INTERVAL`].
+    /// The stream ends after [`PostcardStatusUpdate::Reached`], the first error,
+    /// [`PostcardError::Cancelled`] or [`PostcardError::ReturnedToSender`] once `target` can no longer be reached,
+    /// or [`PostcardError::Timeout`] once `timeout` has elapsed.
+    pub fn watch_postcard_status(
+        &self,
+        id: &PostcardId,
+        target: TrackingEventName,
+        timeout: Duration,
+    ) -> impl Stream<Item = Result<PostcardStatusUpdate, PostcardError>> + use<'a, 'b> {
+        let watch = Watch {
+            client: self.clone(),
+            id: id.clone(),
+            target,
+            deadline: Instant::now() + timeout,
+            interval: POSTCARD_POLL_INTERVAL,
+            polled: false,
+            seen: HashSet::new(),
+            pending: VecDeque::new(),
+            outcome: None,
+            done: false,
         };
 
-        CreatePostcardBuilder::new(self.client.clone(), self.api_key, url)
+        stream::unfold(watch, |mut watch| async move {
+            loop {
+                if let Some(event) = watch.pending.pop_front() {
+                    return Some((Ok(PostcardStatusUpdate::Tracking(event)), watch));
+                }
+                if let Some(outcome) = watch.outcome.take() {
+                    watch.done = true;
+                    let update =
+                        outcome.map(|postcard| PostcardStatusUpdate::Reached(Box::new(postcard)));
+                    return Some((update, watch));
+                }
+                if watch.done {
+                    return None;
+                }
+
+                if watch.polled {
+                    let now = Instant::now();
+                    if now >= watch.deadline {
+                        watch.done = true;
+                        return Some((Err(PostcardError::Timeout(watch.target)), watch));
+                    }
+                    sleep(watch.interval.min(watch.deadline - now)).await;
+                    watch.interval = (watch.interval * 2).min(POSTCARD_POLL_MAX_INTERVAL);
+                }
+                watch.polled = true;
+
+                match watch.client.retrieve_postcard(&watch.id).await {
+                    Ok(postcard) => watch.observe(postcard),
+                    Err(err) => watch.outcome = Some(Err(err)),
+                }
+            }
+        })
+    }
+
+    /// block until one of a postcard's tracking events reaches `target`, e.g. [`TrackingEventName::Mailed`]
+    ///
+    /// Returns the postcard as of that check, see [`LobClient::watch_postcard_status`] for the polling schedule.
+    pub async fn wait_for_status(
+        &self,
+        id: &PostcardId,
+        target: TrackingEventName,
+        timeout: Duration,
+    ) -> Result<Postcard, PostcardError> {
+        let mut updates = pin!(self.watch_postcard_status(id, target, timeout));
+
+        while let Some(update) = updates.next().await {
+            if let PostcardStatusUpdate::Reached(postcard) = update? {
+                return Ok(*postcard);
+            }
+        }
+
+        Err(PostcardError::Timeout(target))
+    }
+
+    /// fetch a rendered file of a postcard once lob has finished rendering it
+    ///
+    /// The postcard is retrieved again for a freshly signed link whenever the file is
+    /// still rendering (`404`) or the signed link has expired (`403`).
+    async fn rendered_file(
+        &self,
+        id: &PostcardId,
+        file: RenderedFile,
+        timeout: Duration,
+    ) -> Result<Response, PostcardError> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let postcard = self.retrieve_postcard(id).await?;
+
+            if let Some(url) = file.url(&postcard) {
+                let response = self.client.get(url).send().await?;
+
+                match response.status() {
+                    StatusCode::NOT_FOUND | StatusCode::FORBIDDEN => {}
+                    _ => return Ok(response.error_for_status()?),
+                }
+            }
+            if Instant::now() + POSTCARD_RENDER_POLL_INTERVAL > deadline {
+                return Err(PostcardError::RenderTimeout);
+            }
+            sleep(POSTCARD_RENDER_POLL_INTERVAL).await;
+        }
+    }
+
+    /// wait for lob to render a postcard, then download the pdf or a thumbnail
+    pub async fn download_postcard_file(
+        &self,
+        id: &PostcardId,
+        file: RenderedFile,
+        timeout: Duration,
+    ) -> Result<Vec<u8>, PostcardError> {
+        let response = self.rendered_file(id, file, timeout).await?;
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// wait for lob to render a postcard, then stream the pdf or a thumbnail into `writer`
+    ///
+    /// Returns the number of bytes written, `writer` is flushed but not shut down.
+    pub async fn download_postcard_file_to<W: AsyncWrite + Unpin>(
+        &self,
+        id: &PostcardId,
+        file: RenderedFile,
+        writer: &mut W,
+        timeout: Duration,
+    ) -> Result<u64, PostcardError> {
+        let mut response = self.rendered_file(id, file, timeout).await?;
+        let mut written = 0;
+
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+}
+
+/// State of [`LobClient::watch_postcard_status`] between checks
+struct Watch<'a, 'b> {
+    client: LobClient<'a, 'b>,
+    id: PostcardId,
+    target: TrackingEventName,
+    deadline: Instant,
+    interval: Duration,
+    polled: bool,
+    seen: HashSet<TrackingEventId>,
+    pending: VecDeque<TrackingEvent>,
+    outcome: Option<Result<Postcard, PostcardError>>,
+    done: bool,
+}
+
+impl Watch<'_, '_> {
+    /// queues the postcard's new tracking events and decides whether watching is over
+    fn observe(&mut self, postcard: Postcard) {
+        for event in &postcard.tracking_events {
+            if self.seen.insert(event.id.clone()) {
+                self.pending.push_back(event.clone());
+            }
+        }
+
+        let reached = |name: TrackingEventName| {
+            postcard
+                .tracking_events
+                .iter()
+                .any(|event| event.name.reaches(name))
+        };
+
+        self.outcome = if reached(self.target) {
+            Some(Ok(postcard))
+        } else if postcard.deleted {
+            Some(Err(PostcardError::Cancelled))
+        } else if reached(TrackingEventName::ReturnedToSender) {
+            Some(Err(PostcardError::ReturnedToSender))
+        } else {
+            None
+        };
     }
 }
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    EventError, EventId, LobClient, LobEvent,
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_EVENTS_URL},
+    list::ListBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// retrieve an event by its id
+    pub async fn retrieve_event(&self, id: &EventId) -> Result<LobEvent, EventError> {
+        let url = build_url(self.base_url, &format!("events/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list the events lob has sent, or would have sent, as webhooks, most recent first
+    ///
+    /// Useful to backfill webhooks missed during an outage with [`ListBuilder::date_created_after`].
+    pub fn list_events<'c>(&self) -> ListBuilder<'a, 'b, 'c, LobEvent, EventError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_EVENTS_URL),
+            false => Cow::Owned(build_url(self.base_url, "events")),
+        };
+
+        ListBuilder::new(self.clone(), url)
+    }
+}
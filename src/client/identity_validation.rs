@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+use crate::{
+    LobClient,
+    constants::{DEFAULT_BASE_URL, DEFAULT_IDENTITY_VALIDATION_URL},
+    identity_validation::builder::IdentityValidationBuilder,
+};
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// create an identity validation request builder, checking a recipient is likely to be found at an address
+    pub fn validate_identity(&self) -> IdentityValidationBuilder<'a, 'b> {
+        use super::build_url::build_url;
+
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_IDENTITY_VALIDATION_URL),
+            false => Cow::Owned(build_url(self.base_url, "identity_validation")),
+        };
+
+        IdentityValidationBuilder::new(self.client.clone(), self.api_key, url)
+    }
+}
@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+use reqwest::{
+    Method,
+    multipart::{Form, Part},
+};
+
+use crate::{
+    CampaignId, Deleted, InformedDeliveryCampaign, InformedDeliveryCampaignId,
+    InformedDeliveryError, LobClient,
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_INFORMED_DELIVERY_CAMPAIGNS_URL},
+    informed_delivery::request::{validate_ride_along_image, validate_target_url},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// set up usps informed delivery for `campaign`
+    ///
+    /// Recipients see `ride_along_image`, a jpeg, next to the preview of their mail and are taken to `target_url` when they click it. The image and url are checked against usps' limits before anything is uploaded.
+    pub async fn create_informed_delivery_campaign(
+        &self,
+        campaign: &CampaignId,
+        target_url: &str,
+        ride_along_image: &[u8],
+    ) -> Result<InformedDeliveryCampaign, InformedDeliveryError> {
+        validate_target_url(target_url)?;
+        validate_ride_along_image(ride_along_image)?;
+
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_INFORMED_DELIVERY_CAMPAIGNS_URL),
+            false => Cow::Owned(build_url(self.base_url, "informed_delivery_campaigns")),
+        };
+
+        let image = Part::bytes(ride_along_image.to_vec())
+            .file_name("ride_along_image.jpg")
+            .mime_str("image/jpeg")?;
+        let form = Form::new()
+            .text("campaign_id", campaign.as_str().to_owned())
+            .text("target_url", target_url.to_owned())
+            .part("ride_along_image", image);
+
+        let request = self.request(Method::POST, url.as_ref()).multipart(form);
+
+        send(request).await
+    }
+
+    /// retrieve an informed delivery campaign by its id
+    pub async fn retrieve_informed_delivery_campaign(
+        &self,
+        id: &InformedDeliveryCampaignId,
+    ) -> Result<InformedDeliveryCampaign, InformedDeliveryError> {
+        let url = build_url(self.base_url, &format!("informed_delivery_campaigns/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// delete an informed delivery campaign, the campaign's mail is no longer previewed
+    pub async fn delete_informed_delivery_campaign(
+        &self,
+        id: &InformedDeliveryCampaignId,
+    ) -> Result<Deleted<InformedDeliveryCampaignId>, InformedDeliveryError> {
+        let url = build_url(self.base_url, &format!("informed_delivery_campaigns/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    ApiError, IntlVerification, IntlVerificationAddress, IntlVerificationError, LobClient,
+    bulk::{BulkRequest, BulkResponse},
+    client::send::send,
+    constants::{
+        APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_BULK_INTL_VERIFICATIONS_URL,
+        DEFAULT_INTL_VERIFICATIONS_URL,
+    },
+    intl_verification::{builder::VerifyIntlAddressBuilder, bulk::INTL_VERIFICATION_BATCH_LIMIT},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// create an international address verification request builder
+    pub fn verify_intl_address(&self) -> VerifyIntlAddressBuilder<'a, 'b> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_INTL_VERIFICATIONS_URL),
+            false => Cow::Owned(build_url(self.base_url, "intl_verifications")),
+        };
+
+        VerifyIntlAddressBuilder::new(self.client.clone(), self.api_key, url)
+    }
+
+    /// verify many international addresses, in batches of [`INTL_VERIFICATION_BATCH_LIMIT`]
+    ///
+    /// The results are in the same order as `addresses`. An address lob rejects is returned as an [`ApiError`] in its slot rather than failing the whole call.
+    /// If a whole batch fails, the results of the batches before it are returned in [`IntlVerificationError::Batch`].
+    pub async fn verify_intl_addresses(
+        &self,
+        addresses: &[IntlVerificationAddress<'_>],
+    ) -> Result<Vec<Result<IntlVerification, ApiError>>, IntlVerificationError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_BULK_INTL_VERIFICATIONS_URL),
+            false => Cow::Owned(build_url(self.base_url, "bulk/intl_verifications")),
+        };
+
+        let mut out = Vec::with_capacity(addresses.len());
+
+        for batch in addresses.chunks(INTL_VERIFICATION_BATCH_LIMIT) {
+            match self.verify_intl_batch(url.as_ref(), batch).await {
+                Ok(response) => out.extend(response.addresses.into_iter().map(Into::into)),
+                Err(err) => {
+                    return Err(IntlVerificationError::Batch {
+                        completed: out,
+                        source: Box::new(err),
+                    });
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    async fn verify_intl_batch(
+        &self,
+        url: &str,
+        batch: &[IntlVerificationAddress<'_>],
+    ) -> Result<BulkResponse<IntlVerification>, IntlVerificationError> {
+        let request = serde_json::to_string(&BulkRequest { addresses: batch })?;
+
+        let request = self
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        let response: BulkResponse<IntlVerification> =
+            send::<_, IntlVerificationError>(request).await?;
+
+        match response.addresses.len() == batch.len() {
+            true => Ok(response),
+            false => Err(IntlVerificationError::BatchLength {
+                sent: batch.len(),
+                received: response.addresses.len(),
+            }),
+        }
+    }
+}
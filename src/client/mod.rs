@@ -8,6 +8,28 @@ pub struct LobClient<'a, 'b> {
     base_url: &'b str,
 }
 
+mod address;
+mod billing_group;
+mod booklet;
+mod buckslip;
 mod build_url;
 mod builder;
+mod campaign;
+mod card;
 mod create_postcard;
+mod event;
+mod identity_validation;
+mod informed_delivery;
+mod intl_verification;
+mod print_order;
+mod qr_code_analytics;
+mod resource_proof;
+mod self_mailer;
+pub(crate) mod send;
+mod snap_pack;
+mod template;
+mod upload;
+mod url_shortener;
+mod us_autocompletion;
+mod us_lookup;
+mod us_verification;
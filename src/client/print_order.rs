@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    Deleted, LobClient, PrintInsert, PrintOrderQuantity,
+    client::send::send,
+    constants::APPLICATION_JSON,
+    list::ListBuilder,
+    print_order::{
+        builder::{CreatePrintInsertBuilder, UpdatePrintInsertBuilder},
+        request::CreatePrintOrderJson,
+    },
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn print_insert_url<K: PrintInsert>(&self, id: &K::Id) -> String {
+        build_url(self.base_url, &format!("{}/{id}", K::PATH))
+    }
+
+    fn print_orders_url<K: PrintInsert>(&self, id: &K::Id) -> String {
+        build_url(self.base_url, &format!("{}/{id}/orders", K::PATH))
+    }
+
+    pub(super) fn create_print_insert<K: PrintInsert>(
+        &self,
+        url: Cow<'b, str>,
+    ) -> CreatePrintInsertBuilder<'a, 'b, K> {
+        CreatePrintInsertBuilder::new(self.client.clone(), self.api_key, url)
+    }
+
+    pub(super) async fn retrieve_print_insert<K: PrintInsert>(
+        &self,
+        id: &K::Id,
+    ) -> Result<K, K::Error> {
+        let url = self.print_insert_url::<K>(id);
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    pub(super) fn update_print_insert<'c, K: PrintInsert>(
+        &self,
+        id: &K::Id,
+    ) -> UpdatePrintInsertBuilder<'a, 'b, 'c, K> {
+        let url = self.print_insert_url::<K>(id);
+
+        UpdatePrintInsertBuilder::new(self.client.clone(), self.api_key, Cow::Owned(url))
+    }
+
+    pub(super) async fn delete_print_insert<K: PrintInsert>(
+        &self,
+        id: &K::Id,
+    ) -> Result<Deleted<K::Id>, K::Error> {
+        let url = self.print_insert_url::<K>(id);
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+
+    pub(super) async fn create_print_order<K: PrintInsert>(
+        &self,
+        id: &K::Id,
+        quantity: PrintOrderQuantity<K>,
+    ) -> Result<K::Order, K::Error> {
+        let url = self.print_orders_url::<K>(id);
+        let request = serde_json::to_string(&CreatePrintOrderJson {
+            quantity: quantity.get(),
+        })?;
+
+        let request = self
+            .request(Method::POST, url.as_str())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    pub(super) fn list_print_orders<'c, K: PrintInsert>(
+        &self,
+        id: &K::Id,
+    ) -> ListBuilder<'a, 'b, 'c, K::Order, K::Error> {
+        let url = self.print_orders_url::<K>(id);
+
+        ListBuilder::new(self.clone(), Cow::Owned(url))
+    }
+}
@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use crate::{
+    LobClient, QrCodeAnalyticsError, QrCodeScans,
+    constants::{DEFAULT_BASE_URL, DEFAULT_QR_CODE_ANALYTICS_URL},
+    list::ListBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// list the qr code scans of your mail pieces, most recently created first
+    ///
+    /// Besides the common list options, results can be narrowed with
+    /// [`ListBuilder::scanned`] and [`ListBuilder::resource_ids`].
+    pub fn list_qr_code_scans<'c>(
+        &self,
+    ) -> ListBuilder<'a, 'b, 'c, QrCodeScans, QrCodeAnalyticsError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_QR_CODE_ANALYTICS_URL),
+            false => Cow::Owned(build_url(self.base_url, "qr_code_analytics")),
+        };
+
+        ListBuilder::new(self.clone(), url)
+    }
+}
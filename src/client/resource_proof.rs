@@ -0,0 +1,76 @@
+use std::{borrow::Cow, time::Duration};
+
+use reqwest::{Method, header::CONTENT_TYPE};
+use tokio::time::{Instant, sleep};
+
+use crate::{
+    LobClient, ProofResource, RESOURCE_PROOF_POLL_INTERVAL, ResourceProof, ResourceProofError,
+    ResourceProofId,
+    client::send::send,
+    constants::{APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_RESOURCE_PROOFS_URL},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// ask lob to render a proof of a built postcard request without mailing it
+    ///
+    /// `resource` is borrowed, so once the proof is approved the same request can be sent.
+    pub async fn create_resource_proof(
+        &self,
+        resource: &impl ProofResource,
+    ) -> Result<ResourceProof, ResourceProofError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_RESOURCE_PROOFS_URL),
+            false => Cow::Owned(build_url(self.base_url, "resource_proofs")),
+        };
+        let request = resource.proof_request()?;
+
+        let request = self
+            .request(Method::POST, url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// retrieve a resource proof by its id
+    pub async fn retrieve_resource_proof(
+        &self,
+        id: &ResourceProofId,
+    ) -> Result<ResourceProof, ResourceProofError> {
+        let url = build_url(self.base_url, &format!("resource_proofs/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// poll a resource proof until lob has rendered it, returning the link to the proof
+    pub async fn wait_for_resource_proof(
+        &self,
+        id: &ResourceProofId,
+        timeout: Duration,
+    ) -> Result<Box<str>, ResourceProofError> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(url) = self.retrieve_resource_proof(id).await?.url {
+                return Ok(url);
+            }
+            if Instant::now() + RESOURCE_PROOF_POLL_INTERVAL > deadline {
+                return Err(ResourceProofError::Timeout);
+            }
+            sleep(RESOURCE_PROOF_POLL_INTERVAL).await;
+        }
+    }
+
+    /// render a proof of a built postcard request and wait for the link to it
+    pub async fn render_proof(
+        &self,
+        resource: &impl ProofResource,
+        timeout: Duration,
+    ) -> Result<Box<str>, ResourceProofError> {
+        let proof = self.create_resource_proof(resource).await?;
+
+        self.wait_for_resource_proof(&proof.id, timeout).await
+    }
+}
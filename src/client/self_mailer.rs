@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    Deleted, LobClient, SelfMailer, SelfMailerError, SelfMailerId, TrackingEvent,
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_SELF_MAILERS_URL},
+    list::ListBuilder,
+    self_mailer::builder::CreateSelfMailerBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn self_mailers_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_SELF_MAILERS_URL),
+            false => Cow::Owned(build_url(self.base_url, "self_mailers")),
+        }
+    }
+
+    /// create a self mailer request builder
+    pub fn create_self_mailer(&self) -> CreateSelfMailerBuilder<'a, 'b> {
+        CreateSelfMailerBuilder::new(self.client.clone(), self.api_key, self.self_mailers_url())
+    }
+
+    /// retrieve a previously created self mailer by its id
+    pub async fn retrieve_self_mailer(
+        &self,
+        id: &SelfMailerId,
+    ) -> Result<SelfMailer, SelfMailerError> {
+        let url = build_url(self.base_url, &format!("self_mailers/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// the full usps tracking history of a self mailer, oldest first
+    ///
+    /// Lob embeds tracking events in the self mailer rather than listing them, so this retrieves it.
+    pub async fn self_mailer_tracking_events(
+        &self,
+        id: &SelfMailerId,
+    ) -> Result<Vec<TrackingEvent>, SelfMailerError> {
+        Ok(self.retrieve_self_mailer(id).await?.tracking_events)
+    }
+
+    /// list self mailers, most recently created first
+    pub fn list_self_mailers<'c>(&self) -> ListBuilder<'a, 'b, 'c, SelfMailer, SelfMailerError> {
+        ListBuilder::new(self.clone(), self.self_mailers_url())
+    }
+
+    /// cancel a self mailer before its send date
+    pub async fn cancel_self_mailer(
+        &self,
+        id: &SelfMailerId,
+    ) -> Result<Deleted<SelfMailerId>, SelfMailerError> {
+        let url = build_url(self.base_url, &format!("self_mailers/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
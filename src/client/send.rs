@@ -0,0 +1,35 @@
+use reqwest::{Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+
+use crate::{ApiError, LobClient, WrapperApiError};
+
+/// Implemented by every endpoint error so responses can be decoded in one place.
+pub trait LobResponseError: From<reqwest::Error> + From<ApiError> {
+    /// Wraps a failure to parse the response body along with the raw body.
+    fn json(err: serde_json::Error, src: String) -> Self;
+}
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// starts an authenticated request against `url`
+    pub(crate) fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .basic_auth::<&str, &str>(self.api_key, None)
+    }
+}
+
+/// Sends `request` and decodes either the Lob error envelope or `T`.
+pub(crate) async fn send<T: DeserializeOwned, E: LobResponseError>(
+    request: RequestBuilder,
+) -> Result<T, E> {
+    let src = request.send().await?.text().await?;
+
+    if let Ok(wrapper_api_error) = serde_json::from_str::<WrapperApiError>(src.as_str()) {
+        return Err(E::from(wrapper_api_error.error));
+    }
+
+    match serde_json::from_str(src.as_str()) {
+        Ok(ok) => Ok(ok),
+        Err(err) => Err(E::json(err, src)),
+    }
+}
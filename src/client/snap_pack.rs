@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    Deleted, LobClient, SnapPack, SnapPackError, SnapPackId, TrackingEvent,
+    client::send::send,
+    constants::{DEFAULT_BASE_URL, DEFAULT_SNAP_PACKS_URL},
+    list::ListBuilder,
+    snap_pack::builder::CreateSnapPackBuilder,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn snap_packs_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_SNAP_PACKS_URL),
+            false => Cow::Owned(build_url(self.base_url, "snap_packs")),
+        }
+    }
+
+    /// create a snap pack request builder
+    pub fn create_snap_pack(&self) -> CreateSnapPackBuilder<'a, 'b> {
+        CreateSnapPackBuilder::new(self.client.clone(), self.api_key, self.snap_packs_url())
+    }
+
+    /// retrieve a previously created snap pack by its id
+    pub async fn retrieve_snap_pack(&self, id: &SnapPackId) -> Result<SnapPack, SnapPackError> {
+        let url = build_url(self.base_url, &format!("snap_packs/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// the full usps tracking history of a snap pack, oldest first
+    ///
+    /// Lob embeds tracking events in the snap pack rather than listing them, so this retrieves it.
+    pub async fn snap_pack_tracking_events(
+        &self,
+        id: &SnapPackId,
+    ) -> Result<Vec<TrackingEvent>, SnapPackError> {
+        Ok(self.retrieve_snap_pack(id).await?.tracking_events)
+    }
+
+    /// list snap packs, most recently created first
+    pub fn list_snap_packs<'c>(&self) -> ListBuilder<'a, 'b, 'c, SnapPack, SnapPackError> {
+        ListBuilder::new(self.clone(), self.snap_packs_url())
+    }
+
+    /// cancel a snap pack before its send date
+    pub async fn cancel_snap_pack(
+        &self,
+        id: &SnapPackId,
+    ) -> Result<Deleted<SnapPackId>, SnapPackError> {
+        let url = build_url(self.base_url, &format!("snap_packs/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
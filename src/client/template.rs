@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    Deleted, LobClient, Template, TemplateError, TemplateId, TemplateVersion, TemplateVersionId,
+    client::send::send,
+    constants::{APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_TEMPLATES_URL},
+    list::ListBuilder,
+    template::{
+        builder::{CreateTemplateBuilder, CreateTemplateVersionBuilder, UpdateTemplateBuilder},
+        request::UpdateTemplateVersionJson,
+    },
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn templates_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_TEMPLATES_URL),
+            false => Cow::Owned(build_url(self.base_url, "templates")),
+        }
+    }
+
+    /// create a template request builder
+    pub fn create_template(&self) -> CreateTemplateBuilder<'a, 'b> {
+        CreateTemplateBuilder::new(self.client.clone(), self.api_key, self.templates_url())
+    }
+
+    /// retrieve a template by its id
+    pub async fn retrieve_template(&self, id: &TemplateId) -> Result<Template, TemplateError> {
+        let url = build_url(self.base_url, &format!("templates/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list templates, most recently created first
+    pub fn list_templates<'c>(&self) -> ListBuilder<'a, 'b, 'c, Template, TemplateError> {
+        ListBuilder::new(self.clone(), self.templates_url())
+    }
+
+    /// create a request builder updating a template's description or published version
+    pub fn update_template<'c, 'd>(
+        &self,
+        id: &TemplateId,
+    ) -> UpdateTemplateBuilder<'a, 'b, 'c, 'd> {
+        let url = build_url(self.base_url, &format!("templates/{id}"));
+
+        UpdateTemplateBuilder::new(self.client.clone(), self.api_key, Cow::Owned(url))
+    }
+
+    /// delete a template and all of its versions
+    pub async fn delete_template(
+        &self,
+        id: &TemplateId,
+    ) -> Result<Deleted<TemplateId>, TemplateError> {
+        let url = build_url(self.base_url, &format!("templates/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+
+    /// create a template version request builder
+    pub fn create_template_version(
+        &self,
+        template: &TemplateId,
+    ) -> CreateTemplateVersionBuilder<'a, 'b> {
+        let url = build_url(self.base_url, &format!("templates/{template}/versions"));
+
+        CreateTemplateVersionBuilder::new(self.client.clone(), self.api_key, Cow::Owned(url))
+    }
+
+    /// retrieve a version of a template by its id
+    pub async fn retrieve_template_version(
+        &self,
+        template: &TemplateId,
+        id: &TemplateVersionId,
+    ) -> Result<TemplateVersion, TemplateError> {
+        let url = build_url(
+            self.base_url,
+            &format!("templates/{template}/versions/{id}"),
+        );
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list the versions of a template, most recently created first
+    pub fn list_template_versions<'c>(
+        &self,
+        template: &TemplateId,
+    ) -> ListBuilder<'a, 'b, 'c, TemplateVersion, TemplateError> {
+        let url = build_url(self.base_url, &format!("templates/{template}/versions"));
+
+        ListBuilder::new(self.clone(), Cow::Owned(url))
+    }
+
+    /// change the description of a template version
+    pub async fn update_template_version(
+        &self,
+        template: &TemplateId,
+        id: &TemplateVersionId,
+        description: &str,
+    ) -> Result<TemplateVersion, TemplateError> {
+        let url = build_url(
+            self.base_url,
+            &format!("templates/{template}/versions/{id}"),
+        );
+
+        let request = serde_json::to_string(&UpdateTemplateVersionJson { description })?;
+
+        let request = self
+            .request(Method::POST, url.as_str())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// delete a version of a template, which cannot be its published version
+    pub async fn delete_template_version(
+        &self,
+        template: &TemplateId,
+        id: &TemplateVersionId,
+    ) -> Result<Deleted<TemplateVersionId>, TemplateError> {
+        let url = build_url(
+            self.base_url,
+            &format!("templates/{template}/versions/{id}"),
+        );
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
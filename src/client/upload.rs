@@ -0,0 +1,171 @@
+use std::{borrow::Cow, path::Path, time::Duration};
+
+use reqwest::{
+    Method,
+    header::CONTENT_TYPE,
+    multipart::{Form, Part},
+};
+use tokio::time::{Instant, sleep};
+
+use crate::{
+    CampaignId, LobClient, UPLOAD_POLL_INTERVAL, Upload, UploadColumnMapping, UploadError,
+    UploadExport, UploadExportCreated, UploadExportId, UploadExportState, UploadExportType,
+    UploadFile, UploadId,
+    client::send::send,
+    constants::{APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_UPLOADS_URL},
+    list::ListBuilder,
+    upload::request::{CreateExportJson, CreateUploadJson},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// create an upload to hold the audience of `campaign`
+    pub async fn create_upload(
+        &self,
+        campaign: &CampaignId,
+        mapping: &UploadColumnMapping<'_>,
+    ) -> Result<Upload, UploadError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_UPLOADS_URL),
+            false => Cow::Owned(build_url(self.base_url, "uploads")),
+        };
+        let request = serde_json::to_string(&CreateUploadJson::new(campaign, mapping))?;
+
+        let request = self
+            .request(Method::POST, url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// send the csv holding an upload's audience, lob starts processing it once received
+    pub async fn upload_file(
+        &self,
+        id: &UploadId,
+        filename: &str,
+        csv: Vec<u8>,
+    ) -> Result<UploadFile, UploadError> {
+        let url = build_url(self.base_url, &format!("uploads/{id}/file"));
+        let file = Part::bytes(csv)
+            .file_name(filename.to_owned())
+            .mime_str("text/csv")?;
+
+        let request = self
+            .request(Method::POST, url.as_str())
+            .multipart(Form::new().part("file", file));
+
+        send(request).await
+    }
+
+    /// retrieve an upload by its id
+    pub async fn retrieve_upload(&self, id: &UploadId) -> Result<Upload, UploadError> {
+        let url = build_url(self.base_url, &format!("uploads/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list uploads, narrow them to one campaign with [`ListBuilder::campaign_id`]
+    ///
+    /// Lob returns every matching upload at once, so the list is always a single page.
+    pub fn list_uploads<'c>(&self) -> ListBuilder<'a, 'b, 'c, Upload, UploadError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_UPLOADS_URL),
+            false => Cow::Owned(build_url(self.base_url, "uploads")),
+        };
+
+        ListBuilder::new(self.clone(), url)
+    }
+
+    /// request an export of an upload's rows, retrieve it once lob has generated it
+    pub async fn create_upload_export(
+        &self,
+        id: &UploadId,
+        export_type: UploadExportType,
+    ) -> Result<UploadExportCreated, UploadError> {
+        let url = build_url(self.base_url, &format!("uploads/{id}/exports"));
+        let request = serde_json::to_string(&CreateExportJson { export_type })?;
+
+        let request = self
+            .request(Method::POST, url.as_str())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// retrieve an export of an upload's rows
+    pub async fn retrieve_upload_export(
+        &self,
+        upload: &UploadId,
+        id: &UploadExportId,
+    ) -> Result<UploadExport, UploadError> {
+        let url = build_url(self.base_url, &format!("uploads/{upload}/exports/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// poll an upload until lob has finished processing its csv
+    pub async fn wait_for_upload(
+        &self,
+        id: &UploadId,
+        timeout: Duration,
+    ) -> Result<Upload, UploadError> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let upload = self.retrieve_upload(id).await?;
+            if upload.original_filename.is_some() && !upload.state.is_processing() {
+                return Ok(upload);
+            }
+            if Instant::now() + UPLOAD_POLL_INTERVAL > deadline {
+                return Err(UploadError::Timeout);
+            }
+            sleep(UPLOAD_POLL_INTERVAL).await;
+        }
+    }
+
+    /// request an export of an upload's rows and poll it until lob has generated it
+    pub async fn export_upload(
+        &self,
+        id: &UploadId,
+        export_type: UploadExportType,
+        timeout: Duration,
+    ) -> Result<UploadExport, UploadError> {
+        let deadline = Instant::now() + timeout;
+        let created = self.create_upload_export(id, export_type).await?;
+
+        loop {
+            let export = self.retrieve_upload_export(id, &created.export_id).await?;
+            if export.state != UploadExportState::InProgress {
+                return Ok(export);
+            }
+            if Instant::now() + UPLOAD_POLL_INTERVAL > deadline {
+                return Err(UploadError::Timeout);
+            }
+            sleep(UPLOAD_POLL_INTERVAL).await;
+        }
+    }
+
+    /// create an upload for `campaign`, send the csv at `path` and wait for lob to process it
+    pub async fn upload_audience(
+        &self,
+        campaign: &CampaignId,
+        mapping: &UploadColumnMapping<'_>,
+        path: impl AsRef<Path>,
+        timeout: Duration,
+    ) -> Result<Upload, UploadError> {
+        let path = path.as_ref();
+        let csv = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .and_then(|filename| filename.to_str())
+            .unwrap_or("audience.csv");
+
+        let upload = self.create_upload(campaign, mapping).await?;
+        self.upload_file(&upload.id, filename, csv).await?;
+
+        self.wait_for_upload(&upload.id, timeout).await
+    }
+}
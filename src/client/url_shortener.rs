@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    Deleted, Domain, DomainId, Link, LinkId, LinkRequest, LobClient, UrlShortenerError,
+    client::send::send,
+    constants::{APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_DOMAINS_URL, DEFAULT_LINKS_URL},
+    list::ListBuilder,
+    url_shortener::request::{BulkLinksRequest, BulkLinksResponse, CreateDomainJson},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    fn domains_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_DOMAINS_URL),
+            false => Cow::Owned(build_url(self.base_url, "domains")),
+        }
+    }
+
+    fn links_url(&self) -> Cow<'b, str> {
+        match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_LINKS_URL),
+            false => Cow::Owned(build_url(self.base_url, "links")),
+        }
+    }
+
+    /// register a domain to serve short links from, its dns must point at lob
+    pub async fn create_domain(&self, domain: &str) -> Result<Domain, UrlShortenerError> {
+        let request = serde_json::to_string(&CreateDomainJson { domain })?;
+
+        let request = self
+            .request(Method::POST, self.domains_url().as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// retrieve a domain by its id
+    pub async fn retrieve_domain(&self, id: &DomainId) -> Result<Domain, UrlShortenerError> {
+        let url = build_url(self.base_url, &format!("domains/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list domains, most recently created first
+    pub fn list_domains<'c>(&self) -> ListBuilder<'a, 'b, 'c, Domain, UrlShortenerError> {
+        ListBuilder::new(self.clone(), self.domains_url())
+    }
+
+    /// delete a domain along with its links
+    pub async fn delete_domain(
+        &self,
+        id: &DomainId,
+    ) -> Result<Deleted<DomainId>, UrlShortenerError> {
+        let url = build_url(self.base_url, &format!("domains/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+
+    /// create a short link
+    pub async fn create_link(&self, link: &LinkRequest<'_>) -> Result<Link, UrlShortenerError> {
+        let request = serde_json::to_string(link)?;
+
+        let request = self
+            .request(Method::POST, self.links_url().as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// create many short links in one request, returned in the same order as `links`
+    pub async fn create_links(
+        &self,
+        links: &[LinkRequest<'_>],
+    ) -> Result<Vec<Link>, UrlShortenerError> {
+        let url = build_url(self.base_url, "links/bulk");
+        let request = serde_json::to_string(&BulkLinksRequest { links })?;
+
+        let request = self
+            .request(Method::POST, url.as_str())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        let response = send::<BulkLinksResponse, UrlShortenerError>(request).await?;
+
+        Ok(response.links)
+    }
+
+    /// retrieve a short link, along with its click count, by its id
+    pub async fn retrieve_link(&self, id: &LinkId) -> Result<Link, UrlShortenerError> {
+        let url = build_url(self.base_url, &format!("links/{id}"));
+
+        send(self.request(Method::GET, url.as_str())).await
+    }
+
+    /// list short links, most recently created first
+    pub fn list_links<'c>(&self) -> ListBuilder<'a, 'b, 'c, Link, UrlShortenerError> {
+        ListBuilder::new(self.clone(), self.links_url())
+    }
+
+    /// delete a short link, it stops redirecting immediately
+    pub async fn delete_link(&self, id: &LinkId) -> Result<Deleted<LinkId>, UrlShortenerError> {
+        let url = build_url(self.base_url, &format!("links/{id}"));
+
+        send(self.request(Method::DELETE, url.as_str())).await
+    }
+}
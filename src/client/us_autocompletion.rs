@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+use crate::{
+    LobClient,
+    constants::{DEFAULT_BASE_URL, DEFAULT_US_AUTOCOMPLETIONS_URL},
+    us_autocompletion::builder::AutocompleteUsAddressBuilder,
+};
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// create a US address autocompletion request builder
+    pub fn autocomplete_us_address(&self) -> AutocompleteUsAddressBuilder<'a, 'b> {
+        use super::build_url::build_url;
+
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_US_AUTOCOMPLETIONS_URL),
+            false => Cow::Owned(build_url(self.base_url, "us_autocompletions")),
+        };
+
+        AutocompleteUsAddressBuilder::new(self.client.clone(), self.api_key, url)
+    }
+}
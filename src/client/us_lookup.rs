@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    LobClient, ZipLookup, ZipLookupError,
+    client::send::send,
+    constants::{
+        APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_US_REVERSE_GEOCODE_LOOKUPS_URL,
+        DEFAULT_US_ZIP_LOOKUPS_URL,
+    },
+    reverse_geocode::builder::ReverseGeocodeBuilder,
+    zip_lookup::request::ZipLookupRequest,
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// look up the cities, counties and type of a 5 digit US zip code
+    pub async fn lookup_zip_code(&self, zip_code: &str) -> Result<ZipLookup, ZipLookupError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_US_ZIP_LOOKUPS_URL),
+            false => Cow::Owned(build_url(self.base_url, "us_zip_lookups")),
+        };
+
+        let request = serde_json::to_string(&ZipLookupRequest { zip_code })?;
+
+        let request = self
+            .request(Method::POST, url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        send(request).await
+    }
+
+    /// create a request builder looking up the US zip codes nearest to a latitude and longitude
+    pub fn reverse_geocode(&self, latitude: f64, longitude: f64) -> ReverseGeocodeBuilder<'a, 'b> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_US_REVERSE_GEOCODE_LOOKUPS_URL),
+            false => Cow::Owned(build_url(self.base_url, "us_reverse_geocode_lookups")),
+        };
+
+        ReverseGeocodeBuilder::new(self.client.clone(), self.api_key, url, latitude, longitude)
+    }
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    ApiError, LobClient, UsVerification, UsVerificationAddress, UsVerificationError,
+    bulk::{BulkRequest, BulkResponse},
+    client::send::send,
+    constants::{
+        APPLICATION_JSON, DEFAULT_BASE_URL, DEFAULT_BULK_US_VERIFICATIONS_URL,
+        DEFAULT_US_VERIFICATIONS_URL,
+    },
+    us_verification::{builder::VerifyUsAddressBuilder, bulk::US_VERIFICATION_BATCH_LIMIT},
+};
+
+use super::build_url::build_url;
+
+impl<'a, 'b> LobClient<'a, 'b> {
+    /// create a US address verification request builder
+    pub fn verify_us_address(&self) -> VerifyUsAddressBuilder<'a, 'b> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_US_VERIFICATIONS_URL),
+            false => Cow::Owned(build_url(self.base_url, "us_verifications")),
+        };
+
+        VerifyUsAddressBuilder::new(self.client.clone(), self.api_key, url)
+    }
+
+    /// verify many US addresses, in batches of [`US_VERIFICATION_BATCH_LIMIT`]
+    ///
+    /// The results are in the same order as `addresses`. An address lob rejects is returned as an [`ApiError`] in its slot rather than failing the whole call.
+    /// If a whole batch fails, the results of the batches before it are returned in [`UsVerificationError::Batch`].
+    pub async fn verify_us_addresses(
+        &self,
+        addresses: &[UsVerificationAddress<'_>],
+    ) -> Result<Vec<Result<UsVerification, ApiError>>, UsVerificationError> {
+        let url = match self.base_url == DEFAULT_BASE_URL {
+            true => Cow::Borrowed(DEFAULT_BULK_US_VERIFICATIONS_URL),
+            false => Cow::Owned(build_url(self.base_url, "bulk/us_verifications")),
+        };
+
+        let mut out = Vec::with_capacity(addresses.len());
+
+        for batch in addresses.chunks(US_VERIFICATION_BATCH_LIMIT) {
+            match self.verify_us_batch(url.as_ref(), batch).await {
+                Ok(response) => out.extend(response.addresses.into_iter().map(Into::into)),
+                Err(err) => {
+                    return Err(UsVerificationError::Batch {
+                        completed: out,
+                        source: Box::new(err),
+                    });
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    async fn verify_us_batch(
+        &self,
+        url: &str,
+        batch: &[UsVerificationAddress<'_>],
+    ) -> Result<BulkResponse<UsVerification>, UsVerificationError> {
+        let request = serde_json::to_string(&BulkRequest { addresses: batch })?;
+
+        let request = self
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(request);
+
+        let response: BulkResponse<UsVerification> =
+            send::<_, UsVerificationError>(request).await?;
+
+        match response.addresses.len() == batch.len() {
+            true => Ok(response),
+            false => Err(UsVerificationError::BatchLength {
+                sent: batch.len(),
+                received: response.addresses.len(),
+            }),
+        }
+    }
+}
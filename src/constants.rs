@@ -2,6 +2,34 @@ use reqwest::header::{HeaderName, HeaderValue};
 
 pub const DEFAULT_BASE_URL: &str = "https://api.lob.com/v1/";
 pub const DEFAULT_POSTCARDS_URL: &str = "https://api.lob.com/v1/postcards";
+pub const DEFAULT_SELF_MAILERS_URL: &str = "https://api.lob.com/v1/self_mailers";
+pub const DEFAULT_SNAP_PACKS_URL: &str = "https://api.lob.com/v1/snap_packs";
+pub const DEFAULT_ADDRESSES_URL: &str = "https://api.lob.com/v1/addresses";
+pub const DEFAULT_BOOKLETS_URL: &str = "https://api.lob.com/v1/booklets";
+pub const DEFAULT_US_VERIFICATIONS_URL: &str = "https://api.lob.com/v1/us_verifications";
+pub const DEFAULT_BULK_US_VERIFICATIONS_URL: &str = "https://api.lob.com/v1/bulk/us_verifications";
+pub const DEFAULT_INTL_VERIFICATIONS_URL: &str = "https://api.lob.com/v1/intl_verifications";
+pub const DEFAULT_BULK_INTL_VERIFICATIONS_URL: &str =
+    "https://api.lob.com/v1/bulk/intl_verifications";
+pub const DEFAULT_US_AUTOCOMPLETIONS_URL: &str = "https://api.lob.com/v1/us_autocompletions";
+pub const DEFAULT_US_ZIP_LOOKUPS_URL: &str = "https://api.lob.com/v1/us_zip_lookups";
+pub const DEFAULT_US_REVERSE_GEOCODE_LOOKUPS_URL: &str =
+    "https://api.lob.com/v1/us_reverse_geocode_lookups";
+pub const DEFAULT_IDENTITY_VALIDATION_URL: &str = "https://api.lob.com/v1/identity_validation";
+pub const DEFAULT_TEMPLATES_URL: &str = "https://api.lob.com/v1/templates";
+pub const DEFAULT_CAMPAIGNS_URL: &str = "https://api.lob.com/v1/campaigns";
+pub const DEFAULT_CREATIVES_URL: &str = "https://api.lob.com/v1/creatives";
+pub const DEFAULT_UPLOADS_URL: &str = "https://api.lob.com/v1/uploads";
+pub const DEFAULT_BILLING_GROUPS_URL: &str = "https://api.lob.com/v1/billing_groups";
+pub const DEFAULT_BUCKSLIPS_URL: &str = "https://api.lob.com/v1/buckslips";
+pub const DEFAULT_CARDS_URL: &str = "https://api.lob.com/v1/cards";
+pub const DEFAULT_QR_CODE_ANALYTICS_URL: &str = "https://api.lob.com/v1/qr_code_analytics";
+pub const DEFAULT_DOMAINS_URL: &str = "https://api.lob.com/v1/domains";
+pub const DEFAULT_LINKS_URL: &str = "https://api.lob.com/v1/links";
+pub const DEFAULT_INFORMED_DELIVERY_CAMPAIGNS_URL: &str =
+    "https://api.lob.com/v1/informed_delivery_campaigns";
+pub const DEFAULT_RESOURCE_PROOFS_URL: &str = "https://api.lob.com/v1/resource_proofs";
+pub const DEFAULT_EVENTS_URL: &str = "https://api.lob.com/v1/events";
 
 pub const APPLICATION_JSON: HeaderValue = HeaderValue::from_static("application/json");
 pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+/// A two letter ISO 3166-1 alpha-2 country code, e.g. `MX`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CountryCode([u8; 2]);
+
+impl CountryCode {
+    /// parses a two letter country code, in either case
+    ///
+    /// Returns `None` if `code` is not exactly two ascii letters.
+    pub fn new(code: &str) -> Option<Self> {
+        match code.as_bytes() {
+            [a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphabetic() => {
+                Some(Self([a.to_ascii_uppercase(), b.to_ascii_uppercase()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// the country code as an uppercase string slice
+    pub fn as_str(&self) -> &str {
+        // both bytes are checked to be ascii letters in `new`
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for CountryCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CountryCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = Box::<str>::deserialize(deserializer)?;
+
+        Self::new(&code).ok_or_else(|| D::Error::custom(format!("invalid country code: {code}")))
+    }
+}
@@ -2,10 +2,14 @@ use std::borrow::Cow;
 
 use serde::Serialize;
 
-use crate::{MailType, Size, UseType};
+use crate::{
+    AddressId, BillingGroupId, IntlVerification, MailType, Size, UsAutocompletionSuggestion,
+    UsVerification, UseType,
+};
 
 use super::request::{
-    CreatePostcardRequest, CreatePostcardRequestNoMerge, JsonRequest, JsonRequestNoMerge, To,
+    CreatePostcardRequest, CreatePostcardRequestNoMerge, JsonRequest, JsonRequestNoMerge,
+    Recipient, To,
 };
 
 /// Builder for creating a create postcard request.
@@ -61,6 +65,20 @@ impl<'a, 'b, 'c> CreatePostcardBuilderWithIdempotencyKey<'a, 'b, 'c> {
             name,
         }
     }
+
+    /// sends the postcard to an address saved to your lob address book
+    pub fn to<'d, 'e, 'f, 'g, 'h, 'i>(
+        self,
+        to: &'d AddressId,
+    ) -> CreatePostcardBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreatePostcardBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Id(to),
+        }
+    }
 }
 
 /// Builder for a create postcard request with a name set.
@@ -88,6 +106,84 @@ impl<'a, 'b, 'c, 'd> CreatePostcardBuilderWithName<'a, 'b, 'c, 'd> {
             address_line_2: None,
         }
     }
+
+    /// sets the address to which the postcard is being sent to the standardized result of a US verification
+    ///
+    /// The verification is used as-is, so check its [`deliverability`](UsVerification::deliverability) before mailing.
+    pub fn verified_address<'e>(
+        self,
+        verification: &'e UsVerification,
+    ) -> CreatePostcardBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'e, 'e, 'e, 'e> {
+        CreatePostcardBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: &verification.primary_line,
+                address_line_2: verification
+                    .secondary_line
+                    .as_deref()
+                    .filter(|line| !line.is_empty()),
+                address_city: &verification.components.city,
+                address_state: &verification.components.state,
+                address_zip: &verification.components.zip_code,
+                address_country: None,
+            }),
+        }
+    }
+
+    /// sets the address to which the postcard is being sent to the standardized result of an international verification
+    ///
+    /// The verification is used as-is, so check its [`deliverability`](IntlVerification::deliverability) before mailing. Only 4x6 postcards can be sent to international destinations.
+    pub fn verified_intl_address<'e>(
+        self,
+        verification: &'e IntlVerification,
+    ) -> CreatePostcardBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'e, 'e, 'e, 'e> {
+        CreatePostcardBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: &verification.primary_line,
+                address_line_2: verification
+                    .secondary_line
+                    .as_deref()
+                    .filter(|line| !line.is_empty()),
+                address_city: &verification.components.city,
+                address_state: &verification.components.state,
+                address_zip: &verification.components.postal_code,
+                address_country: Some(verification.country),
+            }),
+        }
+    }
+
+    /// sets the address to which the postcard is being sent to a US autocompletion suggestion
+    ///
+    /// Suggestions never include a secondary line, so use [`address_line_1`](Self::address_line_1) instead when the recipient needs a unit number.
+    pub fn suggested_address<'e>(
+        self,
+        suggestion: &'e UsAutocompletionSuggestion,
+    ) -> CreatePostcardBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'e, 'e, 'e, 'e> {
+        CreatePostcardBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: &suggestion.primary_line,
+                address_line_2: None,
+                address_city: &suggestion.city,
+                address_state: &suggestion.state,
+                address_zip: &suggestion.zip_code,
+                address_country: None,
+            }),
+        }
+    }
 }
 
 /// Builder for a create postcard request with addresses set.
@@ -186,12 +282,15 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: self.address_line_1,
+                address_line_2: self.address_line_2,
+                address_city: self.city,
+                address_state: self.state,
+                address_zip: zip_code,
+                address_country: None,
+            }),
         }
     }
 }
@@ -202,12 +301,7 @@ pub struct CreatePostcardBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
 }
 
 impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
@@ -230,12 +324,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code: self.zip_code,
+            to: self.to,
             front,
         }
     }
@@ -247,12 +336,7 @@ pub struct CreatePostcardBuilderWithFront<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     front: &'j str,
 }
 
@@ -276,12 +360,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code: self.zip_code,
+            to: self.to,
             front: self.front,
             back,
         }
@@ -294,12 +373,7 @@ pub struct CreatePostcardBuilderWithBack<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j,
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     front: &'j str,
     back: &'k str,
 }
@@ -319,12 +393,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code: self.zip_code,
+            to: self.to,
             front: self.front,
             back: self.back,
             use_type,
@@ -338,12 +407,7 @@ pub struct CreatePostcardBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i,
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     front: &'j str,
     back: &'k str,
     use_type: UseType,
@@ -362,12 +426,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code: self.zip_code,
+            to: self.to,
             front: self.front,
             back: self.back,
             use_type: self.use_type,
@@ -382,12 +441,7 @@ pub struct CreatePostcardBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j,
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     front: &'j str,
     back: &'k str,
     use_type: UseType,
@@ -407,12 +461,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code: self.zip_code,
+            to: self.to,
             front: self.front,
             back: self.back,
             use_type: self.use_type,
@@ -428,12 +477,7 @@ pub struct CreatePostcardBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i,
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     front: &'j str,
     back: &'k str,
     use_type: UseType,
@@ -445,77 +489,98 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
     CreatePostcardBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
 {
     /// sets the "mail type" for the postcard being sent.
-    pub fn description<'l>(
+    pub fn description<'l, 'm, 'n>(
         self,
         description: &'l str,
-    ) -> CreatePostcardBuilderWithDescription<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+    ) -> CreatePostcardBuilderWithDescription<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n>
+    {
         CreatePostcardBuilderWithDescription {
             client: self.client,
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
-            name: self.name,
-            address_line_1: self.address_line_1,
-            address_line_2: self.address_line_2,
-            city: self.city,
-            state: self.state,
-            zip_code: self.zip_code,
+            to: self.to,
             front: self.front,
             back: self.back,
             use_type: self.use_type,
             size: self.size,
             mail_type: self.mail_type,
             description,
+            from: None,
+            billing_group_id: None,
         }
     }
 }
 
 /// Builder for a create postcard request with a description set.
-pub struct CreatePostcardBuilderWithDescription<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+pub struct CreatePostcardBuilderWithDescription<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+> {
     client: reqwest::Client,
     api_key: &'a str,
     url: Cow<'b, str>,
     idempotency_key: &'c str,
-    name: &'d str,
-    address_line_1: &'e str,
-    address_line_2: Option<&'f str>,
-    city: &'g str,
-    state: &'h str,
-    zip_code: &'i str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     front: &'j str,
     back: &'k str,
     use_type: UseType,
     size: Size,
     mail_type: MailType,
     description: &'l str,
+    from: Option<&'m AddressId>,
+    billing_group_id: Option<&'n BillingGroupId>,
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
-    CreatePostcardBuilderWithDescription<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n>
+    CreatePostcardBuilderWithDescription<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n>
 {
+    /// sets the return address of the postcard to one saved to your lob address book
+    pub fn from(self, from: &'m AddressId) -> Self {
+        Self {
+            from: Some(from),
+            ..self
+        }
+    }
+
+    /// bills the postcard to one of your billing groups
+    pub fn billing_group_id(self, billing_group_id: &'n BillingGroupId) -> Self {
+        Self {
+            billing_group_id: Some(billing_group_id),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
-    ) -> CreatePostcardRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+    ) -> CreatePostcardRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n> {
         CreatePostcardRequestNoMerge {
             client: self.client,
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
             json_request: JsonRequestNoMerge {
-                to: To {
-                    name: self.name,
-                    address_line_1: self.address_line_1,
-                    address_line_2: self.address_line_2,
-                    address_city: self.city,
-                    address_state: self.state,
-                    address_zip: self.zip_code,
-                },
+                to: self.to,
                 front: self.front,
                 back: self.back,
                 use_type: self.use_type,
                 size: self.size,
                 mail_type: self.mail_type,
                 description: self.description,
+                from: self.from,
+                billing_group_id: self.billing_group_id,
             },
         }
     }
@@ -523,27 +588,22 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
     pub fn merge<Merge: Serialize>(
         self,
         merge_variables: Merge,
-    ) -> CreatePostcardRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge> {
+    ) -> CreatePostcardRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, Merge> {
         CreatePostcardRequest {
             client: self.client,
             api_key: self.api_key,
             url: self.url,
             idempotency_key: self.idempotency_key,
             json_request: JsonRequest {
-                to: To {
-                    name: self.name,
-                    address_line_1: self.address_line_1,
-                    address_line_2: self.address_line_2,
-                    address_city: self.city,
-                    address_state: self.state,
-                    address_zip: self.zip_code,
-                },
+                to: self.to,
                 front: self.front,
                 back: self.back,
                 use_type: self.use_type,
                 size: self.size,
                 mail_type: self.mail_type,
                 description: self.description,
+                from: self.from,
+                billing_group_id: self.billing_group_id,
                 merge_variables,
             },
         }
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::ApiError;
+use crate::{ApiError, TrackingEventName, client::send::LobResponseError};
 
 /// Errors that can occur while performing a Lob "create postcard" request.
 #[derive(Debug, Error)]
@@ -35,3 +35,59 @@ pub enum CreatePostcardError {
     #[error("Json: {0} - {1}")]
     Json(#[source] serde_json::Error, String),
 }
+
+/// Errors that can occur while retrieving or listing Lob postcards.
+#[derive(Debug, Error)]
+pub enum PostcardError {
+    /// HTTP or network error from [`reqwest`].
+    #[error("Reqwest: {0}")]
+    Reqwest(
+        #[from]
+        #[source]
+        reqwest::Error,
+    ),
+
+    /// An Api Error From Lob
+    #[error("Lob: {0}")]
+    Api(
+        #[from]
+        #[source]
+        ApiError,
+    ),
+
+    /// Failed to parse the JSON response.
+    ///
+    /// Contains the parse error and the raw response body.
+    #[error("Json: {0} - {1}")]
+    Json(#[source] serde_json::Error, String),
+
+    /// The postcard did not reach the target status before the timeout elapsed
+    #[error("Timed out waiting for the postcard to reach {0:?}")]
+    Timeout(TrackingEventName),
+
+    /// The postcard was cancelled, so it will never reach the target status
+    #[error("The postcard was cancelled")]
+    Cancelled,
+
+    /// The postcard is being returned to the sender, so it will never reach the target status
+    #[error("The postcard was returned to sender")]
+    ReturnedToSender,
+
+    /// Lob did not finish rendering the postcard before the timeout elapsed
+    #[error("Timed out waiting for lob to render the postcard")]
+    RenderTimeout,
+
+    /// Failed to write a downloaded render
+    #[error("Io: {0}")]
+    Io(
+        #[from]
+        #[source]
+        std::io::Error,
+    ),
+}
+
+impl LobResponseError for PostcardError {
+    fn json(err: serde_json::Error, src: String) -> Self {
+        Self::Json(err, src)
+    }
+}
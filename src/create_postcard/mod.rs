@@ -1,8 +1,12 @@
 pub(crate) mod builder;
 mod error;
-mod request;
+mod render;
+pub(crate) mod request;
 mod response;
+mod status;
 
-pub use error::CreatePostcardError;
+pub use error::{CreatePostcardError, PostcardError};
+pub use render::{POSTCARD_RENDER_POLL_INTERVAL, RenderedFile, ThumbnailSize};
 pub use request::{MailType, Size, UseType};
-pub use response::CreatePostcardResponse;
+pub use response::{CreatePostcardResponse, Postcard, PostcardId, Thumbnail};
+pub use status::{POSTCARD_POLL_INTERVAL, POSTCARD_POLL_MAX_INTERVAL, PostcardStatusUpdate};
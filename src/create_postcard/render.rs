@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use crate::{Postcard, Thumbnail};
+
+/// How long to wait between checks while lob renders a postcard
+pub const POSTCARD_RENDER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The size of a [`Thumbnail`] render
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThumbnailSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Thumbnail {
+    /// the signed link to the render of this size
+    pub fn url(&self, size: ThumbnailSize) -> &str {
+        match size {
+            ThumbnailSize::Small => &self.small,
+            ThumbnailSize::Medium => &self.medium,
+            ThumbnailSize::Large => &self.large,
+        }
+    }
+}
+
+/// A file lob renders for a mail piece
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RenderedFile {
+    /// the pdf of the whole mail piece
+    Pdf,
+    /// a png of one side, `0` being the front
+    Thumbnail(usize, ThumbnailSize),
+}
+
+impl RenderedFile {
+    pub(crate) fn url(self, postcard: &Postcard) -> Option<&str> {
+        match self {
+            Self::Pdf => postcard.url.as_deref(),
+            Self::Thumbnail(side, size) => postcard.thumbnails.get(side).map(|t| t.url(size)),
+        }
+    }
+}
@@ -1,52 +1,69 @@
 use std::borrow::Cow;
 
 use reqwest::header::CONTENT_TYPE;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    CreatePostcardError, CreatePostcardResponse, WrapperApiError,
+    AddressId, BillingGroupId, CountryCode, CreatePostcardError, CreatePostcardResponse,
+    WrapperApiError,
     constants::{APPLICATION_JSON, IDEMPOTENCY_KEY},
 };
 
-pub struct CreatePostcardRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge> {
+pub struct CreatePostcardRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, Merge> {
     pub(crate) client: reqwest::Client,
     pub(crate) api_key: &'a str,
     pub(crate) url: Cow<'b, str>,
     pub(crate) idempotency_key: &'c str,
-    pub(crate) json_request: JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge>,
+    pub(crate) json_request: JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, Merge>,
 }
 
-pub struct CreatePostcardRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+pub struct CreatePostcardRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n> {
     pub(crate) client: reqwest::Client,
     pub(crate) api_key: &'a str,
     pub(crate) url: Cow<'b, str>,
     pub(crate) idempotency_key: &'c str,
-    pub(crate) json_request: JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>,
+    pub(crate) json_request: JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n>,
 }
 
 #[derive(Serialize)]
-pub struct JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge> {
-    pub(crate) to: To<'d, 'e, 'f, 'g, 'h, 'i>,
+pub struct JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, Merge> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     pub(crate) front: &'j str,
     pub(crate) back: &'k str,
     pub(crate) size: Size,
     pub(crate) mail_type: MailType,
     pub(crate) merge_variables: Merge,
     pub(crate) description: &'l str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) from: Option<&'m AddressId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'n BillingGroupId>,
     pub(crate) use_type: UseType,
 }
 
 #[derive(Serialize)]
-pub struct JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
-    pub(crate) to: To<'d, 'e, 'f, 'g, 'h, 'i>,
+pub struct JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
     pub(crate) front: &'j str,
     pub(crate) back: &'k str,
     pub(crate) size: Size,
     pub(crate) mail_type: MailType,
     pub(crate) description: &'l str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) from: Option<&'m AddressId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'n BillingGroupId>,
     pub(crate) use_type: UseType,
 }
 
+/// The recipient of a mail piece, either written out inline or saved to the address book
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum Recipient<'d, 'e, 'f, 'g, 'h, 'i> {
+    Inline(To<'d, 'e, 'f, 'g, 'h, 'i>),
+    Id(&'d AddressId),
+}
+
 #[derive(Serialize)]
 pub struct To<'d, 'e, 'f, 'g, 'h, 'i> {
     pub(crate) name: &'d str,
@@ -55,11 +72,15 @@ pub struct To<'d, 'e, 'f, 'g, 'h, 'i> {
     #[serde(rename = "address_line2")]
     pub(crate) address_line_2: Option<&'f str>,
     pub(crate) address_city: &'g str,
+    #[serde(skip_serializing_if = "str::is_empty")]
     pub(crate) address_state: &'h str,
+    #[serde(skip_serializing_if = "str::is_empty")]
     pub(crate) address_zip: &'i str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) address_country: Option<CountryCode>,
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 /// Identifying your mail use type helps Lob populate the right mail settings and postage options to ensure your mail is produced and delivered in an optimal way. Lob requires that you identify—or tag—your mail with one of the following use type options:
 pub enum UseType {
@@ -70,7 +91,7 @@ pub enum UseType {
     Operational,
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 /// Specifies the size of the postcard. Only 4x6 postcards can be sent to international destinations.
 pub enum Size {
     #[serde(rename = "4x6")]
@@ -82,7 +103,7 @@ pub enum Size {
 }
 
 /// An enum designating the mail postage type
-#[derive(Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MailType {
     /// usps_first_class - (default)
@@ -91,8 +112,8 @@ pub enum MailType {
     UspsStandard,
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge: Serialize>
-    CreatePostcardRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge>
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, Merge: Serialize>
+    CreatePostcardRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, Merge>
 {
     pub async fn send(self) -> Result<CreatePostcardResponse, CreatePostcardError> {
         let request = serde_json::to_string(&self.json_request)?;
@@ -120,8 +141,8 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, Merge: Serialize>
     }
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
-    CreatePostcardRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n>
+    CreatePostcardRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n>
 {
     pub async fn send(self) -> Result<CreatePostcardResponse, CreatePostcardError> {
         let request = serde_json::to_string(&self.json_request)?;
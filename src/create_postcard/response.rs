@@ -1,10 +1,76 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
+use crate::{Address, MailType, Size, TrackingEvent, UseType, id::lob_id};
+
+lob_id!(
+    /// The id of a postcard, prefixed with `psc_`
+    PostcardId
+);
+
 /// The response from lob's api for creating a postcard
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreatePostcardResponse {
     /// the id of the postcard
-    pub id: Box<str>,
+    pub id: PostcardId,
+    /// the send date
+    pub send_date: Box<str>,
+}
+
+/// A postcard as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Postcard {
+    /// the id of the postcard
+    pub id: PostcardId,
+    /// the description of the postcard
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the recipient of the postcard
+    pub to: Address,
+    /// the sender of the postcard
+    #[serde(default)]
+    pub from: Option<Address>,
+    /// a signed link to the rendered postcard
+    #[serde(default)]
+    pub url: Option<Box<str>>,
+    /// signed links to png renders of each side of the postcard, front first
+    #[serde(default)]
+    pub thumbnails: Vec<Thumbnail>,
+    /// the size of the postcard
+    pub size: Size,
+    /// the postage type of the postcard
+    pub mail_type: MailType,
+    /// the use type of the postcard
+    #[serde(default)]
+    pub use_type: Option<UseType>,
+    /// the metadata attached to the postcard
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
     /// the send date
     pub send_date: Box<str>,
+    /// the expected delivery date
+    #[serde(default)]
+    pub expected_delivery_date: Option<Box<str>>,
+    /// when the postcard was created
+    pub date_created: Box<str>,
+    /// when the postcard was last modified
+    pub date_modified: Box<str>,
+    /// the usps scans of the postcard so far, oldest first
+    #[serde(default)]
+    pub tracking_events: Vec<TrackingEvent>,
+    /// whether the postcard has been cancelled
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+/// Signed links to png renders of one side of a mail piece
+#[derive(Clone, Debug, Deserialize)]
+pub struct Thumbnail {
+    /// the small render
+    pub small: Box<str>,
+    /// the medium render
+    pub medium: Box<str>,
+    /// the large render
+    pub large: Box<str>,
 }
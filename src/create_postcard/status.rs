@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use crate::{Postcard, TrackingEvent};
+
+/// How long to wait before the first re-check of a postcard's tracking events
+pub const POSTCARD_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The longest the wait between checks of a postcard's tracking events grows to
+pub const POSTCARD_POLL_MAX_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Progress of a postcard watched with [`LobClient::watch_postcard_status`](crate::LobClient::watch_postcard_status)
+#[derive(Clone, Debug)]
+pub enum PostcardStatusUpdate {
+    /// a tracking event not seen on an earlier check
+    Tracking(TrackingEvent),
+    /// the postcard reached the target status, always the last update
+    Reached(Box<Postcard>),
+}
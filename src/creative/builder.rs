@@ -0,0 +1,216 @@
+use std::borrow::Cow;
+
+use crate::{
+    AddressId, CampaignId, Creative, CreativeError, MailType, Metadata, Size,
+    client::send::send,
+    constants::APPLICATION_JSON,
+    creative::request::{
+        Artwork, CreateCreativeJson, LetterDetails, PostcardDetails, UpdateCreativeJson,
+    },
+};
+use reqwest::{Method, header::CONTENT_TYPE};
+
+/// The artwork of a postcard creative.
+#[derive(Clone, Copy, Debug)]
+pub struct PostcardCreative<'a> {
+    /// the front of the postcard, as html, a url or a template id
+    pub front: &'a str,
+    /// the back of the postcard, as html, a url or a template id
+    pub back: &'a str,
+    /// the size of every postcard in the campaign
+    pub size: Size,
+    /// the postage type of every postcard in the campaign
+    pub mail_type: MailType,
+}
+
+/// The artwork of a letter creative.
+#[derive(Clone, Copy, Debug)]
+pub struct LetterCreative<'a> {
+    /// the letter's content, as html, a url or a template id
+    pub file: &'a str,
+    /// whether the letter is printed in color
+    pub color: bool,
+    /// the postage type of every letter in the campaign
+    pub mail_type: MailType,
+}
+
+/// Builder for a create creative request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateCreativeBuilder<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    campaign_id: &'c CampaignId,
+}
+
+impl<'a, 'b, 'c> CreateCreativeBuilder<'a, 'b, 'c> {
+    pub(crate) fn new(
+        client: reqwest::Client,
+        api_key: &'a str,
+        url: Cow<'b, str>,
+        campaign_id: &'c CampaignId,
+    ) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            campaign_id,
+        }
+    }
+
+    /// sets the return address of every mail piece using the creative
+    pub fn from<'d>(self, from: &'d AddressId) -> CreateCreativeBuilderWithFrom<'a, 'b, 'c, 'd> {
+        CreateCreativeBuilderWithFrom {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            campaign_id: self.campaign_id,
+            from,
+        }
+    }
+}
+
+/// Builder for a create creative request with a return address set.
+pub struct CreateCreativeBuilderWithFrom<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    campaign_id: &'c CampaignId,
+    from: &'d AddressId,
+}
+
+impl<'a, 'b, 'c, 'd> CreateCreativeBuilderWithFrom<'a, 'b, 'c, 'd> {
+    /// attaches postcard artwork to the campaign
+    pub fn postcard<'e, 'f, 'g>(
+        self,
+        postcard: PostcardCreative<'e>,
+    ) -> CreateCreativeBuilderWithArtwork<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        self.artwork(Artwork::Postcard {
+            front: postcard.front,
+            back: postcard.back,
+            details: PostcardDetails {
+                size: postcard.size,
+                mail_type: postcard.mail_type,
+            },
+        })
+    }
+
+    /// attaches letter artwork to the campaign
+    pub fn letter<'e, 'f, 'g>(
+        self,
+        letter: LetterCreative<'e>,
+    ) -> CreateCreativeBuilderWithArtwork<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        self.artwork(Artwork::Letter {
+            file: letter.file,
+            details: LetterDetails {
+                color: letter.color,
+                mail_type: letter.mail_type,
+            },
+        })
+    }
+
+    fn artwork<'e, 'f, 'g>(
+        self,
+        artwork: Artwork<'e>,
+    ) -> CreateCreativeBuilderWithArtwork<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        CreateCreativeBuilderWithArtwork {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: CreateCreativeJson {
+                campaign_id: self.campaign_id,
+                from: self.from,
+                artwork,
+                description: None,
+                metadata: None,
+            },
+        }
+    }
+}
+
+/// Builder for a create creative request with its artwork set.
+pub struct CreateCreativeBuilderWithArtwork<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: CreateCreativeJson<'c, 'd, 'e, 'f, 'g>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> CreateCreativeBuilderWithArtwork<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    /// sets the description of the creative
+    pub fn description(mut self, description: &'f str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sets the metadata attached to the creative
+    pub fn metadata(mut self, metadata: Metadata<'g>) -> Self {
+        self.json_request.metadata = Some(metadata);
+        self
+    }
+
+    /// sends the create creative request
+    pub async fn send(self) -> Result<Creative, CreativeError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+/// Builder for an update creative request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct UpdateCreativeBuilder<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: UpdateCreativeJson<'c, 'd>,
+}
+
+impl<'a, 'b, 'c, 'd> UpdateCreativeBuilder<'a, 'b, 'c, 'd> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            json_request: UpdateCreativeJson {
+                description: None,
+                metadata: None,
+            },
+        }
+    }
+
+    /// changes the description of the creative
+    pub fn description(mut self, description: &'c str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// replaces the metadata attached to the creative
+    pub fn metadata(mut self, metadata: Metadata<'d>) -> Self {
+        self.json_request.metadata = Some(metadata);
+        self
+    }
+
+    /// sends the update creative request
+    pub async fn send(self) -> Result<Creative, CreativeError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .request(Method::PATCH, self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
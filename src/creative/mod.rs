@@ -0,0 +1,8 @@
+pub(crate) mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use builder::{LetterCreative, PostcardCreative};
+pub use error::CreativeError;
+pub use response::{Creative, CreativeId, CreativeResourceType};
@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+use crate::{AddressId, CampaignId, MailType, Metadata, Size};
+
+#[derive(Serialize)]
+#[serde(tag = "resource_type", rename_all = "snake_case")]
+pub(crate) enum Artwork<'e> {
+    Postcard {
+        front: &'e str,
+        back: &'e str,
+        details: PostcardDetails,
+    },
+    Letter {
+        file: &'e str,
+        details: LetterDetails,
+    },
+}
+
+#[derive(Serialize)]
+pub(crate) struct PostcardDetails {
+    pub(crate) size: Size,
+    pub(crate) mail_type: MailType,
+}
+
+#[derive(Serialize)]
+pub(crate) struct LetterDetails {
+    pub(crate) color: bool,
+    pub(crate) mail_type: MailType,
+}
+
+#[derive(Serialize)]
+pub(crate) struct CreateCreativeJson<'c, 'd, 'e, 'f, 'g> {
+    pub(crate) campaign_id: &'c CampaignId,
+    pub(crate) from: &'d AddressId,
+    #[serde(flatten)]
+    pub(crate) artwork: Artwork<'e>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'f str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'g>>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UpdateCreativeJson<'c, 'd> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'c str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'d>>,
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CampaignId, id::lob_id};
+
+lob_id!(
+    /// The id of a creative, prefixed with `crv_`
+    CreativeId
+);
+
+/// A creative as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Creative {
+    /// the id of the creative
+    pub id: CreativeId,
+    /// the kind of mail piece the creative is for
+    pub resource_type: CreativeResourceType,
+    /// the description of the creative
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the campaign the creative is attached to
+    #[serde(default)]
+    pub campaign_id: Option<CampaignId>,
+    /// links to previews of the rendered creative
+    #[serde(default)]
+    pub template_preview_urls: HashMap<Box<str>, Box<str>>,
+    /// the metadata attached to the creative
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// when the creative was created
+    pub date_created: Box<str>,
+    /// when the creative was last modified
+    pub date_modified: Box<str>,
+    /// whether the creative has been deleted
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+/// The kind of mail piece a creative is for
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreativeResourceType {
+    Postcard,
+    Letter,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+/// The response from lob's api after deleting or cancelling a resource
+#[derive(Clone, Debug, Deserialize)]
+pub struct Deleted<Id = Box<str>> {
+    /// the id of the resource
+    pub id: Id,
+    /// whether the resource was deleted
+    pub deleted: bool,
+}
@@ -0,0 +1,198 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    IdentityValidation, IdentityValidationError, client::send::send, constants::APPLICATION_JSON,
+    identity_validation::request::JsonRequest,
+};
+
+/// Builder for an identity validation request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct IdentityValidationBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> IdentityValidationBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the name of the recipient to validate
+    pub fn name<'c>(self, name: &'c str) -> IdentityValidationBuilderWithName<'a, 'b, 'c> {
+        IdentityValidationBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name,
+        }
+    }
+}
+
+/// Builder for an identity validation request with a name set.
+pub struct IdentityValidationBuilderWithName<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+}
+
+impl<'a, 'b, 'c> IdentityValidationBuilderWithName<'a, 'b, 'c> {
+    /// sets the first address line of the recipient's address
+    pub fn address_line_1<'d, 'e>(
+        self,
+        address_line_1: &'d str,
+    ) -> IdentityValidationBuilderWithAddress<'a, 'b, 'c, 'd, 'e> {
+        IdentityValidationBuilderWithAddress {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1,
+            address_line_2: None,
+        }
+    }
+}
+
+/// Builder for an identity validation request with addresses set.
+pub struct IdentityValidationBuilderWithAddress<'a, 'b, 'c, 'd, 'e> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e> IdentityValidationBuilderWithAddress<'a, 'b, 'c, 'd, 'e> {
+    /// sets the second address line of the recipient's address
+    pub fn address_line_2(self, address_line_2: &'e str) -> Self {
+        Self {
+            address_line_2: Some(address_line_2),
+            ..self
+        }
+    }
+
+    /// sets the city of the recipient's address
+    pub fn city<'f>(
+        self,
+        city: &'f str,
+    ) -> IdentityValidationBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f> {
+        IdentityValidationBuilderWithCity {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city,
+        }
+    }
+}
+
+/// Builder for an identity validation request with a city set.
+pub struct IdentityValidationBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+    city: &'f str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> IdentityValidationBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f> {
+    /// sets the state of the recipient's address
+    pub fn state<'g>(
+        self,
+        state: &'g str,
+    ) -> IdentityValidationBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        IdentityValidationBuilderWithState {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state,
+        }
+    }
+}
+
+/// Builder for an identity validation request with a state set.
+pub struct IdentityValidationBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+    city: &'f str,
+    state: &'g str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> IdentityValidationBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    /// sets the zip code of the recipient's address
+    pub fn zip_code<'h>(
+        self,
+        zip_code: &'h str,
+    ) -> IdentityValidationBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        IdentityValidationBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state: self.state,
+            zip_code,
+        }
+    }
+}
+
+/// Builder for an identity validation request with a zip code set.
+pub struct IdentityValidationBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    name: &'c str,
+    address_line_1: &'d str,
+    address_line_2: Option<&'e str>,
+    city: &'f str,
+    state: &'g str,
+    zip_code: &'h str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+    IdentityValidationBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+{
+    /// sends the identity validation request
+    pub async fn send(self) -> Result<IdentityValidation, IdentityValidationError> {
+        let request = serde_json::to_string(&JsonRequest {
+            recipient: self.name,
+            primary_line: self.address_line_1,
+            secondary_line: self.address_line_2,
+            city: self.city,
+            state: self.state,
+            zip_code: self.zip_code,
+        })?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
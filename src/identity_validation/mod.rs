@@ -0,0 +1,7 @@
+pub(crate) mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::IdentityValidationError;
+pub use response::{IdentityConfidence, IdentityValidation};
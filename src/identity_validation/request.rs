@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(crate) struct JsonRequest<'c, 'd, 'e, 'f, 'g, 'h> {
+    pub(crate) recipient: &'c str,
+    pub(crate) primary_line: &'d str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) secondary_line: Option<&'e str>,
+    pub(crate) city: &'f str,
+    pub(crate) state: &'g str,
+    pub(crate) zip_code: &'h str,
+}
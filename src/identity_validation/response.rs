@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+/// How likely lob thinks a recipient is to be found at an address
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdentityValidation {
+    /// the id of the validation
+    #[serde(default)]
+    pub id: Option<Box<str>>,
+    /// the recipient that was validated
+    pub recipient: Box<str>,
+    /// the primary address line that was validated
+    pub primary_line: Box<str>,
+    /// the secondary address line that was validated
+    #[serde(default)]
+    pub secondary_line: Option<Box<str>>,
+    /// the last line that was validated, combining the city, state and zip code
+    #[serde(default)]
+    pub last_line: Box<str>,
+    /// a score from 0 to 100 of how well the recipient matches the address
+    pub score: Option<f64>,
+    /// the confidence level lob derived from the score
+    pub confidence: IdentityConfidence,
+}
+
+/// The confidence lob has that a recipient is found at an address
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityConfidence {
+    /// A score of 70 or above.
+    High,
+    /// A score from 40 up to 70.
+    Medium,
+    /// A score below 40.
+    Low,
+    /// The recipient could not be matched to the address at all.
+    #[serde(rename = "")]
+    None,
+}
@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use crate::{ApiError, client::send::LobResponseError};
+
+/// Errors that can occur while performing a Lob informed delivery request.
+#[derive(Debug, Error)]
+pub enum InformedDeliveryError {
+    /// HTTP or network error from [`reqwest`].
+    #[error("Reqwest: {0}")]
+    Reqwest(
+        #[from]
+        #[source]
+        reqwest::Error,
+    ),
+
+    /// Failed to serialize the json request
+    #[error("Json: {0}")]
+    Serialize(
+        #[from]
+        #[source]
+        serde_json::Error,
+    ),
+
+    /// An Api Error From Lob
+    #[error("Lob: {0}")]
+    Api(
+        #[from]
+        #[source]
+        ApiError,
+    ),
+
+    /// Failed to parse the JSON response.
+    ///
+    /// Contains the parse error and the raw response body.
+    #[error("Json: {0} - {1}")]
+    Json(#[source] serde_json::Error, String),
+
+    /// The ride-along image is not a jpeg.
+    #[error("Ride-Along Image: not a jpeg")]
+    ImageFormat,
+
+    /// The ride-along image is not the size usps requires.
+    ///
+    /// Usps requires [`RIDE_ALONG_IMAGE_WIDTH`](crate::RIDE_ALONG_IMAGE_WIDTH) by [`RIDE_ALONG_IMAGE_HEIGHT`](crate::RIDE_ALONG_IMAGE_HEIGHT) pixels.
+    #[error("Ride-Along Image Dimensions: {width}x{height}")]
+    ImageDimensions { width: u16, height: u16 },
+
+    /// The ride-along image is larger than [`RIDE_ALONG_IMAGE_MAX_BYTES`](crate::RIDE_ALONG_IMAGE_MAX_BYTES).
+    #[error("Ride-Along Image Size: {0} bytes")]
+    ImageSize(usize),
+
+    /// The target url is not an http(s) url of at most [`TARGET_URL_MAX_LEN`](crate::TARGET_URL_MAX_LEN) characters, or contains control characters.
+    #[error("Target Url: {0}")]
+    TargetUrl(Box<str>),
+}
+
+impl LobResponseError for InformedDeliveryError {
+    fn json(err: serde_json::Error, src: String) -> Self {
+        Self::Json(err, src)
+    }
+}
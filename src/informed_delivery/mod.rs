@@ -0,0 +1,9 @@
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::InformedDeliveryError;
+pub use request::{
+    RIDE_ALONG_IMAGE_HEIGHT, RIDE_ALONG_IMAGE_MAX_BYTES, RIDE_ALONG_IMAGE_WIDTH, TARGET_URL_MAX_LEN,
+};
+pub use response::{InformedDeliveryCampaign, InformedDeliveryCampaignId};
@@ -0,0 +1,84 @@
+use crate::InformedDeliveryError;
+
+/// The width in pixels usps requires of a ride-along image
+pub const RIDE_ALONG_IMAGE_WIDTH: u16 = 300;
+
+/// The height in pixels usps requires of a ride-along image
+pub const RIDE_ALONG_IMAGE_HEIGHT: u16 = 200;
+
+/// The largest ride-along image usps accepts, in bytes
+pub const RIDE_ALONG_IMAGE_MAX_BYTES: usize = 200 * 1024;
+
+/// The longest target url usps accepts
+pub const TARGET_URL_MAX_LEN: usize = 255;
+
+pub(crate) fn validate_target_url(target_url: &str) -> Result<(), InformedDeliveryError> {
+    let valid = target_url.len() <= TARGET_URL_MAX_LEN
+        && (target_url.starts_with("https://") || target_url.starts_with("http://"))
+        && !target_url.chars().any(char::is_control);
+
+    match valid {
+        true => Ok(()),
+        false => Err(InformedDeliveryError::TargetUrl(target_url.into())),
+    }
+}
+
+pub(crate) fn validate_ride_along_image(image: &[u8]) -> Result<(), InformedDeliveryError> {
+    if image.len() > RIDE_ALONG_IMAGE_MAX_BYTES {
+        return Err(InformedDeliveryError::ImageSize(image.len()));
+    }
+
+    let (width, height) = jpeg_dimensions(image).ok_or(InformedDeliveryError::ImageFormat)?;
+    if width != RIDE_ALONG_IMAGE_WIDTH || height != RIDE_ALONG_IMAGE_HEIGHT {
+        return Err(InformedDeliveryError::ImageDimensions { width, height });
+    }
+
+    Ok(())
+}
+
+/// Reads the width and height from the frame header of a jpeg.
+pub(crate) fn jpeg_dimensions(image: &[u8]) -> Option<(u16, u16)> {
+    if !image.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    let mut i = 2;
+    while i + 4 <= image.len() {
+        if image[i] != 0xFF {
+            return None;
+        }
+
+        let marker = image[i + 1];
+        match marker {
+            // fill bytes before a marker
+            0xFF => {
+                i += 1;
+                continue;
+            }
+            // markers without a length
+            0x01 | 0xD0..=0xD7 => {
+                i += 2;
+                continue;
+            }
+            // start of scan or end of image before any frame header
+            0xD9 | 0xDA => return None,
+            _ => {}
+        }
+
+        let length = u16::from_be_bytes([image[i + 2], image[i + 3]]) as usize;
+        let is_frame_header =
+            matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+
+        if is_frame_header {
+            let header = image.get(i + 4..i + 9)?;
+            let height = u16::from_be_bytes([header[1], header[2]]);
+            let width = u16::from_be_bytes([header[3], header[4]]);
+
+            return Some((width, height));
+        }
+
+        i += 2 + length;
+    }
+
+    None
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+use crate::{CampaignId, id::lob_id};
+
+lob_id!(
+    /// The id of an informed delivery campaign, prefixed with `idc_`
+    InformedDeliveryCampaignId
+);
+
+/// An informed delivery campaign as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct InformedDeliveryCampaign {
+    /// the id of the informed delivery campaign
+    pub id: InformedDeliveryCampaignId,
+    /// the campaign whose mail is previewed
+    pub campaign_id: CampaignId,
+    /// where recipients are taken when they click the ride-along image
+    pub target_url: Box<str>,
+    /// a link to the uploaded ride-along image
+    #[serde(default)]
+    pub ride_along_image_url: Option<Box<str>>,
+    /// when the informed delivery campaign was created
+    pub date_created: Box<str>,
+    /// when the informed delivery campaign was last modified
+    pub date_modified: Box<str>,
+}
@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    CountryCode, IntlVerification, IntlVerificationError, client::send::send,
+    constants::APPLICATION_JSON, intl_verification::request::JsonRequest,
+};
+
+/// Builder for an international address verification request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct VerifyIntlAddressBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> VerifyIntlAddressBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the primary address line
+    pub fn primary_line<'c>(
+        self,
+        primary_line: &'c str,
+    ) -> VerifyIntlAddressBuilderWithPrimaryLine<'a, 'b, 'c> {
+        VerifyIntlAddressBuilderWithPrimaryLine {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            primary_line,
+        }
+    }
+}
+
+/// Builder for an international address verification request with a primary line set.
+pub struct VerifyIntlAddressBuilderWithPrimaryLine<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    primary_line: &'c str,
+}
+
+impl<'a, 'b, 'c> VerifyIntlAddressBuilderWithPrimaryLine<'a, 'b, 'c> {
+    /// sets the country of the address, which cannot be `US`
+    pub fn country<'d, 'e, 'f, 'g, 'h>(
+        self,
+        country: CountryCode,
+    ) -> VerifyIntlAddressBuilderWithCountry<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        VerifyIntlAddressBuilderWithCountry {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: JsonRequest {
+                primary_line: self.primary_line,
+                secondary_line: None,
+                city: None,
+                state: None,
+                postal_code: None,
+                country,
+                recipient: None,
+            },
+        }
+    }
+}
+
+/// Builder for an international address verification request with a country set.
+pub struct VerifyIntlAddressBuilderWithCountry<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: JsonRequest<'c, 'd, 'e, 'f, 'g, 'h>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+    VerifyIntlAddressBuilderWithCountry<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+{
+    /// sets the secondary address line
+    pub fn secondary_line(mut self, secondary_line: &'d str) -> Self {
+        self.json_request.secondary_line = Some(secondary_line);
+        self
+    }
+
+    /// sets the city
+    pub fn city(mut self, city: &'e str) -> Self {
+        self.json_request.city = Some(city);
+        self
+    }
+
+    /// sets the state, province or region
+    pub fn state(mut self, state: &'f str) -> Self {
+        self.json_request.state = Some(state);
+        self
+    }
+
+    /// sets the postal code
+    pub fn postal_code(mut self, postal_code: &'g str) -> Self {
+        self.json_request.postal_code = Some(postal_code);
+        self
+    }
+
+    /// sets the intended recipient, which lob passes through to the result
+    pub fn recipient(mut self, recipient: &'h str) -> Self {
+        self.json_request.recipient = Some(recipient);
+        self
+    }
+
+    /// sends the verification request
+    pub async fn send(self) -> Result<IntlVerification, IntlVerificationError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
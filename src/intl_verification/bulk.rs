@@ -0,0 +1,47 @@
+use serde::Serialize;
+
+use crate::CountryCode;
+
+/// The most addresses lob accepts in a single bulk international verification request
+///
+/// [`LobClient::verify_intl_addresses`](crate::LobClient::verify_intl_addresses) splits larger inputs into batches of this size.
+pub const INTL_VERIFICATION_BATCH_LIMIT: usize = 20;
+
+/// An international address to verify in bulk
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct IntlVerificationAddress<'a> {
+    /// the primary address line
+    pub primary_line: &'a str,
+    /// the secondary address line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_line: Option<&'a str>,
+    /// the city
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    /// the state, province or region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+    /// the postal code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<&'a str>,
+    /// the country of the address, which cannot be `US`
+    pub country: CountryCode,
+    /// the intended recipient, which lob passes through to the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<&'a str>,
+}
+
+impl<'a> IntlVerificationAddress<'a> {
+    /// an address with only the required primary line and country set
+    pub fn new(primary_line: &'a str, country: CountryCode) -> Self {
+        Self {
+            primary_line,
+            secondary_line: None,
+            city: None,
+            state: None,
+            postal_code: None,
+            country,
+            recipient: None,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use thiserror::Error;
+
+use crate::{ApiError, IntlVerification, client::send::LobResponseError};
+
+/// Errors that can occur while performing a Lob international verification request.
+#[derive(Debug, Error)]
+pub enum IntlVerificationError {
+    /// HTTP or network error from [`reqwest`].
+    #[error("Reqwest: {0}")]
+    Reqwest(
+        #[from]
+        #[source]
+        reqwest::Error,
+    ),
+
+    /// Failed to serialize the json request
+    #[error("Json: {0}")]
+    Serialize(
+        #[from]
+        #[source]
+        serde_json::Error,
+    ),
+
+    /// An Api Error From Lob
+    #[error("Lob: {0}")]
+    Api(
+        #[from]
+        #[source]
+        ApiError,
+    ),
+
+    /// Failed to parse the JSON response.
+    ///
+    /// Contains the parse error and the raw response body.
+    #[error("Json: {0} - {1}")]
+    Json(#[source] serde_json::Error, String),
+
+    /// Lob returned a different number of results than addresses sent in a batch.
+    ///
+    /// The results cannot be matched back to their addresses, so none of the batch is returned.
+    #[error("Batch Length: sent {sent} addresses, received {received} results")]
+    BatchLength {
+        /// the number of addresses in the batch
+        sent: usize,
+        /// the number of results lob returned
+        received: usize,
+    },
+
+    /// A batch of a bulk verification failed.
+    ///
+    /// Contains the results of every batch before it, in the same order as the input, so addresses already verified are not lost.
+    #[error("Batch: {source}")]
+    Batch {
+        /// the results of the addresses before the failed batch
+        completed: Vec<Result<IntlVerification, ApiError>>,
+        /// why the batch failed
+        #[source]
+        source: Box<IntlVerificationError>,
+    },
+}
+
+impl LobResponseError for IntlVerificationError {
+    fn json(err: serde_json::Error, src: String) -> Self {
+        Self::Json(err, src)
+    }
+}
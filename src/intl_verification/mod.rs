@@ -0,0 +1,9 @@
+pub(crate) mod builder;
+pub(crate) mod bulk;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use bulk::{INTL_VERIFICATION_BATCH_LIMIT, IntlVerificationAddress};
+pub use error::IntlVerificationError;
+pub use response::{IntlComponents, IntlDeliverability, IntlVerification};
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+use crate::CountryCode;
+
+#[derive(Serialize)]
+pub(crate) struct JsonRequest<'c, 'd, 'e, 'f, 'g, 'h> {
+    pub(crate) primary_line: &'c str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) secondary_line: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) city: Option<&'e str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<&'f str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) postal_code: Option<&'g str>,
+    pub(crate) country: CountryCode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) recipient: Option<&'h str>,
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+use crate::CountryCode;
+
+/// The result of verifying an international address with lob
+#[derive(Clone, Debug, Deserialize)]
+pub struct IntlVerification {
+    /// the id of the verification
+    #[serde(default)]
+    pub id: Option<Box<str>>,
+    /// the intended recipient, if one was provided
+    #[serde(default)]
+    pub recipient: Option<Box<str>>,
+    /// the standardized primary address line
+    pub primary_line: Box<str>,
+    /// the standardized secondary address line
+    #[serde(default)]
+    pub secondary_line: Option<Box<str>>,
+    /// the standardized last line, combining the city, state and postal code
+    #[serde(default)]
+    pub last_line: Box<str>,
+    /// the country of the address
+    pub country: CountryCode,
+    /// how much of the country lob's verification data covers, e.g. `SUBBUILDING`
+    #[serde(default)]
+    pub coverage: Box<str>,
+    /// how deliverable the address is
+    pub deliverability: IntlDeliverability,
+    /// the precision the address was verified to, from `LV4` (premise) down to `LF0` (failed)
+    #[serde(default)]
+    pub status: Box<str>,
+    /// the parts of the standardized address
+    pub components: IntlComponents,
+}
+
+/// Summarizes the deliverability of a verified international address
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntlDeliverability {
+    /// The address is deliverable.
+    Deliverable,
+    /// The address is deliverable, but is missing information such as a unit number.
+    DeliverableMissingInfo,
+    /// The address is not deliverable.
+    Undeliverable,
+    /// The address could not be matched against lob's data for the country.
+    NoMatch,
+}
+
+/// The parts of a standardized international address
+#[derive(Clone, Debug, Deserialize)]
+pub struct IntlComponents {
+    /// the house or building number
+    #[serde(default)]
+    pub primary_number: Box<str>,
+    /// the street name
+    #[serde(default)]
+    pub street_name: Box<str>,
+    /// the city
+    #[serde(default)]
+    pub city: Box<str>,
+    /// the state, province or region
+    #[serde(default)]
+    pub state: Box<str>,
+    /// the postal code
+    #[serde(default)]
+    pub postal_code: Box<str>,
+}
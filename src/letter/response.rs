@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{Address, MailType, TrackingEvent, UseType, id::lob_id};
+
+lob_id!(
+    /// The id of a letter, prefixed with `ltr_`
+    LetterId
+);
+
+/// A letter as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Letter {
+    /// the id of the letter
+    pub id: LetterId,
+    /// the description of the letter
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the recipient of the letter
+    pub to: Address,
+    /// the sender of the letter
+    pub from: Address,
+    /// a signed link to the rendered letter
+    #[serde(default)]
+    pub url: Option<Box<str>>,
+    /// whether the letter is printed in color
+    #[serde(default)]
+    pub color: bool,
+    /// whether the letter is printed on both sides of each page
+    #[serde(default)]
+    pub double_sided: bool,
+    /// the postage type of the letter
+    pub mail_type: MailType,
+    /// the use type of the letter
+    #[serde(default)]
+    pub use_type: Option<UseType>,
+    /// any extra service, `certified`, `certified_return_receipt` or `registered`
+    #[serde(default)]
+    pub extra_service: Option<Box<str>>,
+    /// the usps tracking number of a certified or registered letter
+    #[serde(default)]
+    pub tracking_number: Option<Box<str>>,
+    /// the metadata attached to the letter
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// the send date
+    pub send_date: Box<str>,
+    /// the expected delivery date
+    #[serde(default)]
+    pub expected_delivery_date: Option<Box<str>>,
+    /// when the letter was created
+    pub date_created: Box<str>,
+    /// when the letter was last modified
+    pub date_modified: Box<str>,
+    /// the usps scans of the letter so far, oldest first
+    #[serde(default)]
+    pub tracking_events: Vec<TrackingEvent>,
+    /// whether the letter has been cancelled
+    #[serde(default)]
+    pub deleted: bool,
+}
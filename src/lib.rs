@@ -1,14 +1,116 @@
 #![doc = include_str!("../README.md")]
 
+mod address;
 mod api_error;
+mod billing_group;
+mod booklet;
+mod buckslip;
+mod bulk;
+mod campaign;
+mod card;
 mod client;
 pub(crate) mod constants;
+mod country_code;
 mod create_postcard;
+mod creative;
+mod deleted;
+mod id;
+mod identity_validation;
+mod informed_delivery;
+mod intl_verification;
+mod letter;
+mod list;
+mod metadata;
+mod print_order;
+mod qr_code_analytics;
+mod resource_proof;
+mod reverse_geocode;
+mod self_mailer;
+mod snap_pack;
+mod template;
+mod tracking_event;
+mod upload;
+mod url_shortener;
+mod us_autocompletion;
+mod us_verification;
+mod webhook;
+mod zip_lookup;
 
+pub use address::{Address, AddressError, AddressId, DeletedAddress};
 pub use api_error::ApiError;
 pub(crate) use api_error::WrapperApiError;
+pub use billing_group::{BillingGroup, BillingGroupError, BillingGroupId};
+pub use booklet::{
+    BOOKLET_MAX_PAGES, BOOKLET_MIN_PAGES, Booklet, BookletError, BookletId, BookletSize,
+};
+pub use buckslip::{
+    BUCKSLIP_ORDER_MIN_QUANTITY, Buckslip, BuckslipError, BuckslipId, BuckslipOrder,
+    BuckslipOrderId, BuckslipOrderQuantity, BuckslipSize,
+};
+pub use campaign::{Campaign, CampaignError, CampaignId, ScheduleType};
+pub use card::{
+    CARD_ORDER_MIN_QUANTITY, Card, CardError, CardId, CardOrder, CardOrderId, CardOrderQuantity,
+    CardSize,
+};
 pub use client::LobClient;
-pub use create_postcard::{CreatePostcardError, CreatePostcardResponse, MailType, Size, UseType};
+pub use country_code::CountryCode;
+pub use create_postcard::{
+    CreatePostcardError, CreatePostcardResponse, MailType, POSTCARD_POLL_INTERVAL,
+    POSTCARD_POLL_MAX_INTERVAL, POSTCARD_RENDER_POLL_INTERVAL, Postcard, PostcardError, PostcardId,
+    PostcardStatusUpdate, RenderedFile, Size, Thumbnail, ThumbnailSize, UseType,
+};
+pub use creative::{
+    Creative, CreativeError, CreativeId, CreativeResourceType, LetterCreative, PostcardCreative,
+};
+pub use deleted::Deleted;
+pub use identity_validation::{IdentityConfidence, IdentityValidation, IdentityValidationError};
+pub use informed_delivery::{
+    InformedDeliveryCampaign, InformedDeliveryCampaignId, InformedDeliveryError,
+    RIDE_ALONG_IMAGE_HEIGHT, RIDE_ALONG_IMAGE_MAX_BYTES, RIDE_ALONG_IMAGE_WIDTH,
+    TARGET_URL_MAX_LEN,
+};
+pub use intl_verification::{
+    INTL_VERIFICATION_BATCH_LIMIT, IntlComponents, IntlDeliverability, IntlVerification,
+    IntlVerificationAddress, IntlVerificationError,
+};
+pub use letter::{Letter, LetterId};
+pub use list::{Cursor, List, ListBuilder};
+pub use metadata::Metadata;
+pub use print_order::{PrintInsert, PrintOrderQuantity, PrintOrderStatus};
+pub use qr_code_analytics::{QrCodeAnalyticsError, QrCodeScan, QrCodeScans};
+pub use resource_proof::{
+    ProofResource, RESOURCE_PROOF_POLL_INTERVAL, ResourceProof, ResourceProofError, ResourceProofId,
+};
+pub use reverse_geocode::{
+    LocationAnalysis, ReverseGeocode, ReverseGeocodeAddress, ReverseGeocodeComponents,
+    ReverseGeocodeError,
+};
+pub use self_mailer::{SelfMailer, SelfMailerError, SelfMailerId, SelfMailerSize};
+pub use snap_pack::{SnapPack, SnapPackError, SnapPackId, SnapPackSize};
+pub use template::{
+    Template, TemplateEngine, TemplateError, TemplateId, TemplateMergeVariables, TemplateVersion,
+    TemplateVersionId,
+};
+pub use tracking_event::{TrackingEvent, TrackingEventId, TrackingEventName};
+pub use upload::{
+    UPLOAD_POLL_INTERVAL, Upload, UploadColumnMapping, UploadError, UploadExport,
+    UploadExportCreated, UploadExportId, UploadExportState, UploadExportType, UploadFile, UploadId,
+    UploadState,
+};
+pub use url_shortener::{Domain, DomainId, Link, LinkId, LinkRequest, UrlShortenerError};
+pub use us_autocompletion::{UsAutocompletion, UsAutocompletionError, UsAutocompletionSuggestion};
+pub use us_verification::{
+    Deliverability, DeliverabilityAnalysis, DpvConfirmation, US_VERIFICATION_BATCH_LIMIT,
+    UsComponents, UsVerification, UsVerificationAddress, UsVerificationError,
+};
+pub use webhook::{
+    AddressEvent, DEFAULT_WEBHOOK_TOLERANCE, Event, EventError, EventId, LOB_SIGNATURE,
+    LOB_SIGNATURE_TIMESTAMP, LetterEvent, LobEvent, MailEvent, WebhookSignatureError,
+    verify_webhook_signature, verify_webhook_signature_at,
+};
+#[cfg(feature = "axum")]
+pub use webhook::{DEFAULT_WEBHOOK_DEDUPE_CAPACITY, WebhookReceiver};
+pub use zip_lookup::{ZipCodeType, ZipLookup, ZipLookupCity, ZipLookupError};
 
 #[cfg(test)]
 mod test;
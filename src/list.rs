@@ -0,0 +1,282 @@
+use std::{borrow::Cow, fmt, marker::PhantomData};
+
+use futures_util::{Stream, stream};
+use reqwest::{Method, Url};
+use serde::{
+    Deserialize, Deserializer,
+    de::{
+        DeserializeOwned, MapAccess, SeqAccess, Visitor,
+        value::{MapAccessDeserializer, SeqAccessDeserializer},
+    },
+};
+
+use crate::{
+    LobClient, Metadata,
+    client::send::{LobResponseError, send},
+    constants::DEFAULT_BASE_URL,
+};
+
+/// A page of resources returned from one of lob's list endpoints
+///
+/// The few endpoints that answer with a bare array, like uploads, deserialize as a single page.
+#[derive(Clone, Debug)]
+pub struct List<T> {
+    /// the resources on this page
+    pub data: Vec<T>,
+    /// the number of resources on this page
+    pub count: u32,
+    /// the total number of resources matching the request,
+    /// only present when requested with [`ListBuilder::include_total_count`]
+    pub total_count: Option<u32>,
+    /// the url of the next page, if there is one
+    pub next_url: Option<Box<str>>,
+    /// the url of the previous page, if there is one
+    pub previous_url: Option<Box<str>>,
+}
+
+#[derive(Deserialize)]
+struct Page<T> {
+    data: Vec<T>,
+    count: u32,
+    #[serde(default)]
+    total_count: Option<u32>,
+    #[serde(default)]
+    next_url: Option<Box<str>>,
+    #[serde(default)]
+    previous_url: Option<Box<str>>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for List<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+            type Value = List<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a list object or an array")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                let data = Vec::<T>::deserialize(SeqAccessDeserializer::new(seq))?;
+
+                Ok(List {
+                    count: data.len() as u32,
+                    data,
+                    total_count: None,
+                    next_url: None,
+                    previous_url: None,
+                })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                let page = Page::<T>::deserialize(MapAccessDeserializer::new(map))?;
+
+                Ok(List {
+                    data: page.data,
+                    count: page.count,
+                    total_count: page.total_count,
+                    next_url: page.next_url,
+                    previous_url: page.previous_url,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(ListVisitor(PhantomData))
+    }
+}
+
+impl<T> List<T> {
+    /// the cursor of the next page, taken from `next_url`
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::parse(self.next_url.as_deref()?, "after").map(Cursor::After)
+    }
+
+    /// the cursor of the previous page, taken from `previous_url`
+    pub fn previous_cursor(&self) -> Option<Cursor> {
+        Cursor::parse(self.previous_url.as_deref()?, "before").map(Cursor::Before)
+    }
+}
+
+/// A position in a paginated list, passed to [`ListBuilder::cursor`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cursor {
+    /// the page before this cursor
+    Before(Box<str>),
+    /// the page after this cursor
+    After(Box<str>),
+}
+
+impl Cursor {
+    fn parse(url: &str, key: &str) -> Option<Box<str>> {
+        // lob sends absolute urls, but a relative one is resolved against the default api
+        let url = Url::parse(DEFAULT_BASE_URL).ok()?.join(url).ok()?;
+
+        url.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into())
+    }
+
+    fn query_pair(&self) -> (&'static str, &str) {
+        match self {
+            Self::Before(cursor) => ("before", cursor),
+            Self::After(cursor) => ("after", cursor),
+        }
+    }
+}
+
+/// Builder for a request to one of lob's list endpoints.
+///
+/// Returned from the `list_*` methods on [`LobClient`].
+pub struct ListBuilder<'a, 'b, 'c, T, E> {
+    client: LobClient<'a, 'b>,
+    url: Cow<'b, str>,
+    limit: Option<u8>,
+    cursor: Option<&'c Cursor>,
+    metadata: Option<Metadata<'c>>,
+    date_created_after: Option<&'c str>,
+    date_created_before: Option<&'c str>,
+    include_total_count: bool,
+    filters: Vec<(&'static str, Cow<'c, str>)>,
+    _marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl<'a, 'b, 'c, T: DeserializeOwned, E: LobResponseError> ListBuilder<'a, 'b, 'c, T, E> {
+    pub(crate) fn new(client: LobClient<'a, 'b>, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            url,
+            limit: None,
+            cursor: None,
+            metadata: None,
+            date_created_after: None,
+            date_created_before: None,
+            include_total_count: false,
+            filters: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// adds an endpoint specific query parameter
+    pub(crate) fn filter(mut self, key: &'static str, value: Cow<'c, str>) -> Self {
+        self.filters.push((key, value));
+        self
+    }
+
+    /// sets how many results to return per page, lob allows between 1 and 100 (default 10)
+    pub fn limit(self, limit: u8) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// returns the page before or after a cursor from [`List::previous_cursor`] or [`List::next_cursor`]
+    pub fn cursor(self, cursor: &'c Cursor) -> Self {
+        Self {
+            cursor: Some(cursor),
+            ..self
+        }
+    }
+
+    /// only returns resources whose metadata matches every pair
+    pub fn metadata(self, metadata: Metadata<'c>) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    /// only returns resources created after `date`, in ISO 8601 format
+    pub fn date_created_after(self, date: &'c str) -> Self {
+        Self {
+            date_created_after: Some(date),
+            ..self
+        }
+    }
+
+    /// only returns resources created before `date`, in ISO 8601 format
+    pub fn date_created_before(self, date: &'c str) -> Self {
+        Self {
+            date_created_before: Some(date),
+            ..self
+        }
+    }
+
+    /// asks lob to fill in [`List::total_count`]
+    pub fn include_total_count(self) -> Self {
+        Self {
+            include_total_count: true,
+            ..self
+        }
+    }
+
+    async fn send_page(&self, cursor: Option<&Cursor>) -> Result<List<T>, E> {
+        let mut request = self.client.request(Method::GET, self.url.as_ref());
+
+        if let Some(limit) = self.limit {
+            request = request.query(&[("limit", limit)]);
+        }
+        if let Some(cursor) = cursor {
+            request = request.query(&[cursor.query_pair()]);
+        }
+        if let Some(metadata) = self.metadata {
+            request = request.query(&metadata.query_pairs());
+        }
+        if let Some(date) = self.date_created_after {
+            request = request.query(&[("date_created[gt]", date)]);
+        }
+        if let Some(date) = self.date_created_before {
+            request = request.query(&[("date_created[lt]", date)]);
+        }
+        if self.include_total_count {
+            request = request.query(&[("include[]", "total_count")]);
+        }
+        if !self.filters.is_empty() {
+            request = request.query(&self.filters);
+        }
+
+        send(request).await
+    }
+
+    /// sends the list request
+    pub async fn send(self) -> Result<List<T>, E> {
+        self.send_page(self.cursor).await
+    }
+
+    /// streams every resource, fetching pages as they are consumed.
+    ///
+    /// Starts from the [`cursor`](Self::cursor), if any, and keeps going in its direction.
+    /// The stream ends after the last page or the first error.
+    pub fn stream(self) -> impl Stream<Item = Result<T, E>> {
+        let cursor = self.cursor.cloned();
+        let state = (self, cursor, Vec::new().into_iter(), false);
+
+        stream::unfold(
+            state,
+            |(builder, mut cursor, mut page, mut done)| async move {
+                loop {
+                    if let Some(item) = page.next() {
+                        return Some((Ok(item), (builder, cursor, page, done)));
+                    }
+                    if done {
+                        return None;
+                    }
+
+                    // an empty page can still point at more resources, so keep following the cursor
+                    match builder.send_page(cursor.as_ref()).await {
+                        Ok(list) => {
+                            cursor = match cursor {
+                                Some(Cursor::Before(_)) => list.previous_cursor(),
+                                _ => list.next_cursor(),
+                            };
+                            done = cursor.is_none();
+                            page = list.data.into_iter();
+                        }
+                        Err(err) => return Some((Err(err), (builder, None, page, true))),
+                    }
+                }
+            },
+        )
+    }
+}
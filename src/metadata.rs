@@ -0,0 +1,27 @@
+use serde::{Serialize, ser::SerializeMap};
+
+/// Key-value pairs attached to a Lob resource.
+///
+/// Lob allows up to 20 keys, each up to 40 characters, with values up to 500 characters.
+#[derive(Clone, Copy, Debug)]
+pub struct Metadata<'m>(pub &'m [(&'m str, &'m str)]);
+
+impl<'m> Metadata<'m> {
+    /// the query string pairs used to filter list endpoints by metadata
+    pub(crate) fn query_pairs(&self) -> Vec<(String, &'m str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (format!("metadata[{key}]"), *value))
+            .collect()
+    }
+}
+
+impl Serialize for Metadata<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,161 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use reqwest::{Method, header::CONTENT_TYPE};
+
+use crate::{
+    PrintInsert, PrintOrderQuantity,
+    client::send::send,
+    constants::APPLICATION_JSON,
+    print_order::request::{CreatePrintInsertJson, UpdatePrintInsertJson},
+};
+
+/// Builder for a create buckslip or card request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreatePrintInsertBuilder<'a, 'b, K> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    insert: PhantomData<fn() -> K>,
+}
+
+impl<'a, 'b, K: PrintInsert> CreatePrintInsertBuilder<'a, 'b, K> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            insert: PhantomData,
+        }
+    }
+
+    /// sets the artwork printed on the front, as a link to a pdf, png or jpg
+    pub fn front<'c, 'd, 'e>(
+        self,
+        front: &'c str,
+    ) -> CreatePrintInsertBuilderWithFront<'a, 'b, 'c, 'd, 'e, K> {
+        CreatePrintInsertBuilderWithFront {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: CreatePrintInsertJson {
+                front,
+                back: None,
+                description: None,
+                size: None,
+                auto_reorder: None,
+                reorder_quantity: None,
+            },
+        }
+    }
+}
+
+/// Builder for a create buckslip or card request with front artwork set.
+pub struct CreatePrintInsertBuilderWithFront<'a, 'b, 'c, 'd, 'e, K: PrintInsert> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: CreatePrintInsertJson<'c, 'd, 'e, K::Size>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, K: PrintInsert> CreatePrintInsertBuilderWithFront<'a, 'b, 'c, 'd, 'e, K> {
+    /// sets the artwork printed on the back, as a link to a pdf, png or jpg
+    pub fn back(mut self, back: &'d str) -> Self {
+        self.json_request.back = Some(back);
+        self
+    }
+
+    /// sets the description of the insert
+    pub fn description(mut self, description: &'e str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sets the size of the insert
+    pub fn size(mut self, size: K::Size) -> Self {
+        self.json_request.size = Some(size);
+        self
+    }
+
+    /// has lob reorder `reorder_quantity` inserts whenever stock runs low
+    pub fn auto_reorder(mut self, reorder_quantity: PrintOrderQuantity<K>) -> Self {
+        self.json_request.auto_reorder = Some(true);
+        self.json_request.reorder_quantity = Some(reorder_quantity.get());
+        self
+    }
+
+    /// sends the create request
+    pub async fn send(self) -> Result<K, K::Error> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+/// Builder for an update buckslip or card request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct UpdatePrintInsertBuilder<'a, 'b, 'c, K> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: UpdatePrintInsertJson<'c>,
+    insert: PhantomData<fn() -> K>,
+}
+
+impl<'a, 'b, 'c, K: PrintInsert> UpdatePrintInsertBuilder<'a, 'b, 'c, K> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            json_request: UpdatePrintInsertJson {
+                description: None,
+                auto_reorder: None,
+                reorder_quantity: None,
+            },
+            insert: PhantomData,
+        }
+    }
+
+    /// changes the description of the insert
+    pub fn description(mut self, description: &'c str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// has lob reorder `reorder_quantity` inserts whenever stock runs low
+    pub fn auto_reorder(mut self, reorder_quantity: PrintOrderQuantity<K>) -> Self {
+        self.json_request.auto_reorder = Some(true);
+        self.json_request.reorder_quantity = Some(reorder_quantity.get());
+        self
+    }
+
+    /// stops lob from reordering the insert automatically
+    pub fn disable_auto_reorder(mut self) -> Self {
+        self.json_request.auto_reorder = Some(false);
+        self.json_request.reorder_quantity = None;
+        self
+    }
+
+    /// sends the update request
+    pub async fn send(self) -> Result<K, K::Error> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .request(Method::PATCH, self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::client::send::LobResponseError;
+
+/// An insert lob prints in bulk and attaches to mail: a [`Buckslip`](crate::Buckslip) or a
+/// [`Card`](crate::Card).
+///
+/// Both share their builders, order quantities and endpoints, which differ only in this.
+pub trait PrintInsert: DeserializeOwned {
+    /// the id of the insert
+    type Id: Display + DeserializeOwned;
+    /// the sizes the insert is printed in
+    type Size: Serialize;
+    /// a print order for the insert
+    type Order: DeserializeOwned;
+    /// errors from requests about the insert
+    type Error: LobResponseError + From<serde_json::Error>;
+
+    /// the path of the insert's endpoints, e.g. `"buckslips"`
+    const PATH: &'static str;
+
+    /// the fewest inserts lob prints in a single order
+    const MIN_ORDER_QUANTITY: u32;
+}
+
+/// Defines the response types, error and quantity of a [`PrintInsert`], which only differ between
+/// buckslips and cards by name.
+macro_rules! print_insert {
+    (
+        noun: $noun:literal,
+        path: $path:literal,
+        size: $size:ty,
+        min_order_quantity: $min:expr,
+        $(#[$insert_meta:meta])* insert: $insert:ident,
+        $(#[$id_meta:meta])* id: $id:ident,
+        $(#[$order_meta:meta])* order: $order:ident { $insert_id:ident },
+        $(#[$order_id_meta:meta])* order_id: $order_id:ident,
+        $(#[$quantity_meta:meta])* quantity: $quantity:ident,
+        $(#[$error_meta:meta])* error: $error:ident $(,)?
+    ) => {
+        $crate::id::lob_id!($(#[$id_meta])* $id);
+
+        $crate::id::lob_id!($(#[$order_id_meta])* $order_id);
+
+        $(#[$insert_meta])*
+        #[derive(Clone, Debug, serde::Deserialize)]
+        pub struct $insert {
+            #[doc = concat!("the id of the ", $noun)]
+            pub id: $id,
+            #[doc = concat!("the description of the ", $noun)]
+            #[serde(default)]
+            pub description: Option<Box<str>>,
+            #[doc = concat!("the size of the ", $noun)]
+            pub size: $size,
+            /// a link to the artwork printed on the front
+            #[serde(default)]
+            pub front_original_url: Option<Box<str>>,
+            /// a link to the artwork printed on the back
+            #[serde(default)]
+            pub back_original_url: Option<Box<str>>,
+            #[doc = concat!("how many ", $noun, "s are in stock and not yet allocated to mail")]
+            #[serde(default)]
+            pub available_quantity: u32,
+            #[doc = concat!("how many ", $noun, "s are allocated to mail that has not been printed yet")]
+            #[serde(default)]
+            pub allocated_quantity: u32,
+            #[doc = concat!("how many ", $noun, "s are physically in stock")]
+            #[serde(default)]
+            pub onhand_quantity: u32,
+            #[doc = concat!("how many ", $noun, "s are ordered but not printed yet")]
+            #[serde(default)]
+            pub pending_quantity: u32,
+            #[doc = concat!("whether lob reorders the ", $noun, " when stock runs low")]
+            #[serde(default)]
+            pub auto_reorder: bool,
+            #[doc = concat!("how many ", $noun, "s lob reorders at a time")]
+            #[serde(default)]
+            pub reorder_quantity: Option<u32>,
+            #[doc = concat!("when the ", $noun, " was created")]
+            pub date_created: Box<str>,
+            #[doc = concat!("when the ", $noun, " was last modified")]
+            pub date_modified: Box<str>,
+        }
+
+        $(#[$order_meta])*
+        #[derive(Clone, Debug, serde::Deserialize)]
+        pub struct $order {
+            /// the id of the order
+            pub id: $order_id,
+            #[doc = concat!("the ", $noun, " being printed")]
+            pub $insert_id: $id,
+            /// where the order is in production
+            pub status: $crate::PrintOrderStatus,
+            #[doc = concat!("how many ", $noun, "s were ordered")]
+            pub quantity_ordered: u32,
+            #[doc = concat!("how many ", $noun, "s from the order are still in stock")]
+            #[serde(default)]
+            pub inventory: u32,
+            #[doc = concat!("the price of each ", $noun, " in dollars, e.g. `\"0.02\"`")]
+            #[serde(default)]
+            pub unit_price: Option<Box<str>>,
+            /// why the order was cancelled
+            #[serde(default)]
+            pub cancelled_reason: Option<Box<str>>,
+            #[doc = concat!("when the ", $noun, "s are expected to be in stock")]
+            #[serde(default)]
+            pub expected_availability_date: Option<Box<str>>,
+            #[doc = concat!("when the ", $noun, "s came into stock")]
+            #[serde(default)]
+            pub availability_date: Option<Box<str>>,
+            /// when the order was created
+            pub date_created: Box<str>,
+            /// when the order was last modified
+            pub date_modified: Box<str>,
+        }
+
+        $(#[$quantity_meta])*
+        pub type $quantity = $crate::PrintOrderQuantity<$insert>;
+
+        $(#[$error_meta])*
+        #[derive(Debug, thiserror::Error)]
+        pub enum $error {
+            /// HTTP or network error from [`reqwest`].
+            #[error("Reqwest: {0}")]
+            Reqwest(
+                #[from]
+                #[source]
+                reqwest::Error,
+            ),
+
+            /// Failed to serialize the json request
+            #[error("Json: {0}")]
+            Serialize(
+                #[from]
+                #[source]
+                serde_json::Error,
+            ),
+
+            /// An Api Error From Lob
+            #[error("Lob: {0}")]
+            Api(
+                #[from]
+                #[source]
+                $crate::ApiError,
+            ),
+
+            /// Failed to parse the JSON response.
+            ///
+            /// Contains the parse error and the raw response body.
+            #[error("Json: {0} - {1}")]
+            Json(#[source] serde_json::Error, String),
+        }
+
+        impl $crate::client::send::LobResponseError for $error {
+            fn json(err: serde_json::Error, src: String) -> Self {
+                Self::Json(err, src)
+            }
+        }
+
+        impl $crate::PrintInsert for $insert {
+            type Id = $id;
+            type Size = $size;
+            type Order = $order;
+            type Error = $error;
+
+            const PATH: &'static str = $path;
+
+            const MIN_ORDER_QUANTITY: u32 = $min;
+        }
+    };
+}
+
+pub(crate) use print_insert;
@@ -0,0 +1,9 @@
+pub(crate) mod builder;
+mod insert;
+pub(crate) mod request;
+mod response;
+
+pub use insert::PrintInsert;
+pub(crate) use insert::print_insert;
+pub use request::PrintOrderQuantity;
+pub use response::PrintOrderStatus;
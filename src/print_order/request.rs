@@ -0,0 +1,88 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::Serialize;
+
+use crate::PrintInsert;
+
+/// How many inserts to print in one order or reorder.
+///
+/// Lob prints at least [`PrintInsert::MIN_ORDER_QUANTITY`] of an insert at a time, so a quantity
+/// can only be built from a count at or above that minimum.
+pub struct PrintOrderQuantity<K> {
+    quantity: u32,
+    insert: PhantomData<fn() -> K>,
+}
+
+impl<K: PrintInsert> PrintOrderQuantity<K> {
+    /// returns [`None`] if `quantity` is below [`PrintInsert::MIN_ORDER_QUANTITY`]
+    pub fn new(quantity: u32) -> Option<Self> {
+        match quantity >= K::MIN_ORDER_QUANTITY {
+            true => Some(Self {
+                quantity,
+                insert: PhantomData,
+            }),
+            false => None,
+        }
+    }
+}
+
+impl<K> PrintOrderQuantity<K> {
+    /// the quantity as a plain count
+    pub fn get(self) -> u32 {
+        self.quantity
+    }
+}
+
+impl<K> Clone for PrintOrderQuantity<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for PrintOrderQuantity<K> {}
+
+impl<K> fmt::Debug for PrintOrderQuantity<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PrintOrderQuantity")
+            .field(&self.quantity)
+            .finish()
+    }
+}
+
+impl<K> PartialEq for PrintOrderQuantity<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.quantity == other.quantity
+    }
+}
+
+impl<K> Eq for PrintOrderQuantity<K> {}
+
+#[derive(Serialize)]
+pub(crate) struct CreatePrintOrderJson {
+    pub(crate) quantity: u32,
+}
+
+#[derive(Serialize)]
+pub(crate) struct CreatePrintInsertJson<'c, 'd, 'e, S> {
+    pub(crate) front: &'c str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) back: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'e str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<S>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) auto_reorder: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reorder_quantity: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UpdatePrintInsertJson<'c> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'c str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) auto_reorder: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reorder_quantity: Option<u32>,
+}
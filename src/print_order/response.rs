@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// Where a print order for buckslips or cards is in production
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PrintOrderStatus {
+    /// the order has been placed and is waiting to be printed
+    Pending,
+    /// the order is being printed
+    Printing,
+    /// the printed inserts are in stock and can be attached to mail
+    Available,
+    /// the order was cancelled before it was printed
+    Cancelled,
+    /// every insert from the order has been used
+    Depleted,
+}
@@ -0,0 +1,20 @@
+use std::borrow::Cow;
+
+use crate::{ListBuilder, PostcardId, QrCodeAnalyticsError, QrCodeScans};
+
+impl<'a, 'b, 'c> ListBuilder<'a, 'b, 'c, QrCodeScans, QrCodeAnalyticsError> {
+    /// only returns mail pieces whose qr code has, or has not, been scanned
+    pub fn scanned(self, scanned: bool) -> Self {
+        self.filter(
+            "scanned",
+            Cow::Borrowed(if scanned { "true" } else { "false" }),
+        )
+    }
+
+    /// only returns the scans of these mail pieces
+    pub fn resource_ids(self, resource_ids: &'c [PostcardId]) -> Self {
+        resource_ids.iter().fold(self, |builder, resource_id| {
+            builder.filter("resource_ids[]", Cow::Borrowed(resource_id.as_str()))
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod builder;
+mod error;
+mod response;
+
+pub use error::QrCodeAnalyticsError;
+pub use response::{QrCodeScan, QrCodeScans};
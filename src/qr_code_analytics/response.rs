@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use crate::PostcardId;
+
+/// The qr code scans of a single mail piece
+#[derive(Clone, Debug, Deserialize)]
+pub struct QrCodeScans {
+    /// the id of the scanned mail piece, as returned by [`CreatePostcardResponse`](crate::CreatePostcardResponse)
+    pub resource_id: PostcardId,
+    /// how many times the qr code was scanned
+    pub number_of_scans: u32,
+    /// every scan of the qr code
+    #[serde(default)]
+    pub scans: Vec<QrCodeScan>,
+}
+
+/// A single scan of a mail piece's qr code
+#[derive(Clone, Debug, Deserialize)]
+pub struct QrCodeScan {
+    /// roughly where the qr code was scanned, based on the scanner's ip address
+    #[serde(default)]
+    pub ip_location: Option<Box<str>>,
+    /// when the qr code was scanned
+    pub scan_date: Box<str>,
+}
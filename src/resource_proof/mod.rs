@@ -0,0 +1,7 @@
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::ResourceProofError;
+pub use request::{ProofResource, RESOURCE_PROOF_POLL_INTERVAL};
+pub use response::{ResourceProof, ResourceProofId};
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::create_postcard::request::{CreatePostcardRequest, CreatePostcardRequestNoMerge};
+
+/// How long to wait between checks while lob renders a resource proof
+pub const RESOURCE_PROOF_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Implemented by built mail piece requests lob can render a proof of.
+///
+/// Accepted by [`LobClient::create_resource_proof`](crate::LobClient::create_resource_proof) and [`LobClient::render_proof`](crate::LobClient::render_proof).
+pub trait ProofResource {
+    /// the json body of a resource proof request for this mail piece
+    fn proof_request(&self) -> Result<String, serde_json::Error>;
+}
+
+#[derive(Serialize)]
+struct ProofJson<'p, P> {
+    resource_type: &'static str,
+    resource_parameters: &'p P,
+}
+
+impl<Merge: Serialize> ProofResource
+    for CreatePostcardRequest<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, Merge>
+{
+    fn proof_request(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&ProofJson {
+            resource_type: "postcard",
+            resource_parameters: &self.json_request,
+        })
+    }
+}
+
+impl ProofResource
+    for CreatePostcardRequestNoMerge<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>
+{
+    fn proof_request(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&ProofJson {
+            resource_type: "postcard",
+            resource_parameters: &self.json_request,
+        })
+    }
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use crate::id::lob_id;
+
+lob_id!(
+    /// The id of a resource proof, prefixed with `rp_`
+    ResourceProofId
+);
+
+/// A resource proof as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResourceProof {
+    /// the id of the resource proof
+    pub id: ResourceProofId,
+    /// the kind of mail piece the proof is of, e.g. `postcard`
+    pub resource_type: Box<str>,
+    /// a signed link to the rendered proof, set once lob has rendered it
+    #[serde(default)]
+    pub url: Option<Box<str>>,
+    /// when the resource proof was created
+    pub date_created: Box<str>,
+    /// when the resource proof was last modified
+    pub date_modified: Box<str>,
+}
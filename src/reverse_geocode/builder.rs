@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    ReverseGeocode, ReverseGeocodeError, client::send::send, constants::APPLICATION_JSON,
+    reverse_geocode::request::JsonRequest,
+};
+
+/// Builder for a US reverse geocode lookup request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct ReverseGeocodeBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    size: Option<u8>,
+    json_request: JsonRequest,
+}
+
+impl<'a, 'b> ReverseGeocodeBuilder<'a, 'b> {
+    pub(crate) fn new(
+        client: reqwest::Client,
+        api_key: &'a str,
+        url: Cow<'b, str>,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            size: None,
+            json_request: JsonRequest {
+                latitude,
+                longitude,
+            },
+        }
+    }
+
+    /// sets how many zip codes to return, lob allows between 1 and 50 (default 5)
+    pub fn size(self, size: u8) -> Self {
+        Self {
+            size: Some(size),
+            ..self
+        }
+    }
+
+    /// sends the reverse geocode lookup request
+    pub async fn send(self) -> Result<ReverseGeocode, ReverseGeocodeError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let mut request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        if let Some(size) = self.size {
+            request = request.query(&[("size", size)]);
+        }
+
+        send(request).await
+    }
+}
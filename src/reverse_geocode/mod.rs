@@ -0,0 +1,9 @@
+pub(crate) mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::ReverseGeocodeError;
+pub use response::{
+    LocationAnalysis, ReverseGeocode, ReverseGeocodeAddress, ReverseGeocodeComponents,
+};
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(crate) struct JsonRequest {
+    pub(crate) latitude: f64,
+    pub(crate) longitude: f64,
+}
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+/// The zip codes lob found nearest to a latitude and longitude
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReverseGeocode {
+    /// the id of the lookup
+    #[serde(default)]
+    pub id: Option<Box<str>>,
+    /// the nearest zip codes, closest first
+    pub addresses: Vec<ReverseGeocodeAddress>,
+}
+
+/// A zip code near the looked up coordinates
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReverseGeocodeAddress {
+    /// the zip code
+    pub components: ReverseGeocodeComponents,
+    /// where the zip code is and how far it is from the looked up coordinates
+    pub location_analysis: LocationAnalysis,
+}
+
+/// The zip code of a reverse geocoded address
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReverseGeocodeComponents {
+    /// the 5 digit zip code
+    pub zip_code: Box<str>,
+    /// the 4 digit zip code extension
+    #[serde(default)]
+    pub zip_code_plus_4: Box<str>,
+}
+
+/// Where a reverse geocoded zip code is
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct LocationAnalysis {
+    /// the latitude of the zip code
+    pub latitude: f64,
+    /// the longitude of the zip code
+    pub longitude: f64,
+    /// the distance from the looked up coordinates, in miles
+    pub distance: f64,
+}
@@ -0,0 +1,629 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::{
+    AddressId, BillingGroupId, MailType, Metadata, UseType,
+    create_postcard::request::{Recipient, To},
+};
+
+use super::request::{
+    CreateSelfMailerRequest, CreateSelfMailerRequestNoMerge, JsonRequest, JsonRequestNoMerge,
+    SelfMailerSize,
+};
+
+/// Builder for creating a create self mailer request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateSelfMailerBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateSelfMailerBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the idempotency key for the request. Idempotent requests are requests that can be called many times without producing different outcomes.
+    ///
+    /// You can safely retry the same request with the same Idempotency Key and be assured that no duplicate self mailers are created even if the API is called multiple times within 24 hours.
+    pub fn idempotency_key<'c>(
+        self,
+        idempotency_key: &'c str,
+    ) -> CreateSelfMailerBuilderWithIdempotencyKey<'a, 'b, 'c> {
+        CreateSelfMailerBuilderWithIdempotencyKey {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with an idempotency key set.
+pub struct CreateSelfMailerBuilderWithIdempotencyKey<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+}
+
+impl<'a, 'b, 'c> CreateSelfMailerBuilderWithIdempotencyKey<'a, 'b, 'c> {
+    /// sets the name for the address to which the self mailer is being sent
+    pub fn name<'d>(self, name: &'d str) -> CreateSelfMailerBuilderWithName<'a, 'b, 'c, 'd> {
+        CreateSelfMailerBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name,
+        }
+    }
+
+    /// sends the self mailer to an address saved to your lob address book
+    pub fn to<'d, 'e, 'f, 'g, 'h, 'i>(
+        self,
+        to: &'d AddressId,
+    ) -> CreateSelfMailerBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreateSelfMailerBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Id(to),
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a name set.
+pub struct CreateSelfMailerBuilderWithName<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+}
+
+impl<'a, 'b, 'c, 'd> CreateSelfMailerBuilderWithName<'a, 'b, 'c, 'd> {
+    /// sets the first address line for the address to which the self mailer is being sent
+    pub fn address_line_1<'e, 'f>(
+        self,
+        address_line_1: &'e str,
+    ) -> CreateSelfMailerBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+        CreateSelfMailerBuilderWithAddress {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1,
+            address_line_2: None,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with addresses set.
+pub struct CreateSelfMailerBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> CreateSelfMailerBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+    /// sets the second address line for the address to which the self mailer is being sent
+    pub fn address_line_2(self, address_line_2: &'f str) -> Self {
+        Self {
+            address_line_2: Some(address_line_2),
+            ..self
+        }
+    }
+
+    /// sets the city for the address to which the self mailer is being sent
+    pub fn city<'g>(
+        self,
+        city: &'g str,
+    ) -> CreateSelfMailerBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        CreateSelfMailerBuilderWithCity {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a city set.
+pub struct CreateSelfMailerBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+    city: &'g str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> CreateSelfMailerBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    /// sets the state for the address to which the self mailer is being sent
+    pub fn state<'h>(
+        self,
+        state: &'h str,
+    ) -> CreateSelfMailerBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        CreateSelfMailerBuilderWithState {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a state set.
+pub struct CreateSelfMailerBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+    city: &'g str,
+    state: &'h str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+    CreateSelfMailerBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+{
+    /// sets the zip code for the address to which the self mailer is being sent
+    pub fn zip_code<'i>(
+        self,
+        zip_code: &'i str,
+    ) -> CreateSelfMailerBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreateSelfMailerBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: self.address_line_1,
+                address_line_2: self.address_line_2,
+                address_city: self.city,
+                address_state: self.state,
+                address_zip: zip_code,
+                address_country: None,
+            }),
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a zip code set.
+pub struct CreateSelfMailerBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+    CreateSelfMailerBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+{
+    /// sets the inside template of the self mailer being sent.
+    ///
+    /// The artwork to use as the inside of your self mailer.
+    ///
+    /// ## Notes:
+    ///
+    /// HTML merge variables should not include delimiting whitespace.
+    /// PDF, PNG, and JPGs must be sized at 6.25"x18.25", 11.25"x9.25", or 12.25"x9.25" at 300 DPI, while supplied HTML will be rendered to the specified size.
+    pub fn inside<'j>(
+        self,
+        inside: &'j str,
+    ) -> CreateSelfMailerBuilderWithInside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+        CreateSelfMailerBuilderWithInside {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            inside,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with inside template set.
+pub struct CreateSelfMailerBuilderWithInside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    inside: &'j str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+    CreateSelfMailerBuilderWithInside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+{
+    /// sets the outside template of the self mailer being sent.
+    ///
+    /// The artwork to use as the outside of your self mailer.
+    ///
+    /// ## Notes:
+    ///
+    /// HTML merge variables should not include delimiting whitespace.
+    /// PDF, PNG, and JPGs must be sized at 6.25"x18.25", 11.25"x9.25", or 12.25"x9.25" at 300 DPI, while supplied HTML will be rendered to the specified size.
+    pub fn outside<'k>(
+        self,
+        outside: &'k str,
+    ) -> CreateSelfMailerBuilderWithOutside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateSelfMailerBuilderWithOutside {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            inside: self.inside,
+            outside,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with outside template set.
+pub struct CreateSelfMailerBuilderWithOutside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    inside: &'j str,
+    outside: &'k str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateSelfMailerBuilderWithOutside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the "usage type" for the self mailer being sent.
+    ///
+    /// The use type for each mailpiece. Can be one of marketing, operational. For more information on use_type, see lob's Help Center article.
+    pub fn use_type(
+        self,
+        use_type: UseType,
+    ) -> CreateSelfMailerBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateSelfMailerBuilderWithUseType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            inside: self.inside,
+            outside: self.outside,
+            use_type,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a use type set.
+pub struct CreateSelfMailerBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    inside: &'j str,
+    outside: &'k str,
+    use_type: UseType,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateSelfMailerBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the "size" for the self mailer being sent.
+    pub fn size(
+        self,
+        size: SelfMailerSize,
+    ) -> CreateSelfMailerBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateSelfMailerBuilderWithSize {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            inside: self.inside,
+            outside: self.outside,
+            use_type: self.use_type,
+            size,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a self mailer size set.
+pub struct CreateSelfMailerBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    inside: &'j str,
+    outside: &'k str,
+    use_type: UseType,
+    size: SelfMailerSize,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateSelfMailerBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the "mail type" for the self mailer being sent.
+    pub fn mail_type(
+        self,
+        mail_type: MailType,
+    ) -> CreateSelfMailerBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateSelfMailerBuilderWithMailType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            inside: self.inside,
+            outside: self.outside,
+            use_type: self.use_type,
+            size: self.size,
+            mail_type,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a mail type set.
+pub struct CreateSelfMailerBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    inside: &'j str,
+    outside: &'k str,
+    use_type: UseType,
+    size: SelfMailerSize,
+    mail_type: MailType,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateSelfMailerBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the description of the self mailer being sent.
+    pub fn description<'l, 'm, 'n, 'o, 'p>(
+        self,
+        description: &'l str,
+    ) -> CreateSelfMailerBuilderWithDescription<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+        'p,
+    > {
+        CreateSelfMailerBuilderWithDescription {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            inside: self.inside,
+            outside: self.outside,
+            use_type: self.use_type,
+            size: self.size,
+            mail_type: self.mail_type,
+            description,
+            metadata: None,
+            send_date: None,
+            from: None,
+            billing_group_id: None,
+        }
+    }
+}
+
+/// Builder for a create self mailer request with a description set.
+pub struct CreateSelfMailerBuilderWithDescription<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+    'p,
+> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    inside: &'j str,
+    outside: &'k str,
+    use_type: UseType,
+    size: SelfMailerSize,
+    mail_type: MailType,
+    description: &'l str,
+    metadata: Option<Metadata<'m>>,
+    send_date: Option<&'n str>,
+    from: Option<&'o AddressId>,
+    billing_group_id: Option<&'p BillingGroupId>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+    CreateSelfMailerBuilderWithDescription<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+        'p,
+    >
+{
+    /// sets the metadata attached to the self mailer being sent.
+    pub fn metadata(self, metadata: Metadata<'m>) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    /// sets the date the self mailer will be sent.
+    ///
+    /// A timestamp in ISO 8601 format which specifies a date after the current time and up to 180 days in the future to send the self mailer off for production. Until the send date, the self mailer can be cancelled.
+    pub fn send_date(self, send_date: &'n str) -> Self {
+        Self {
+            send_date: Some(send_date),
+            ..self
+        }
+    }
+
+    /// sets the return address of the self mailer to one saved to your lob address book
+    pub fn from(self, from: &'o AddressId) -> Self {
+        Self {
+            from: Some(from),
+            ..self
+        }
+    }
+
+    /// bills the self mailer to one of your billing groups
+    pub fn billing_group_id(self, billing_group_id: &'p BillingGroupId) -> Self {
+        Self {
+            billing_group_id: Some(billing_group_id),
+            ..self
+        }
+    }
+
+    pub fn build(
+        self,
+    ) -> CreateSelfMailerRequestNoMerge<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+        'p,
+    > {
+        CreateSelfMailerRequestNoMerge {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            json_request: JsonRequestNoMerge {
+                to: self.to,
+                inside: self.inside,
+                outside: self.outside,
+                use_type: self.use_type,
+                size: self.size,
+                mail_type: self.mail_type,
+                description: self.description,
+                from: self.from,
+                billing_group_id: self.billing_group_id,
+                metadata: self.metadata,
+                send_date: self.send_date,
+            },
+        }
+    }
+
+    pub fn merge<Merge: Serialize>(
+        self,
+        merge_variables: Merge,
+    ) -> CreateSelfMailerRequest<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+        'p,
+        Merge,
+    > {
+        CreateSelfMailerRequest {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            json_request: JsonRequest {
+                to: self.to,
+                inside: self.inside,
+                outside: self.outside,
+                use_type: self.use_type,
+                size: self.size,
+                mail_type: self.mail_type,
+                description: self.description,
+                from: self.from,
+                billing_group_id: self.billing_group_id,
+                metadata: self.metadata,
+                send_date: self.send_date,
+                merge_variables,
+            },
+        }
+    }
+}
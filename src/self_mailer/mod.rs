@@ -0,0 +1,8 @@
+pub(crate) mod builder;
+mod error;
+mod request;
+mod response;
+
+pub use error::SelfMailerError;
+pub use request::SelfMailerSize;
+pub use response::{SelfMailer, SelfMailerId};
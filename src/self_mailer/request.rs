@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AddressId, BillingGroupId, MailType, Metadata, SelfMailer, SelfMailerError, UseType,
+    client::send::send,
+    constants::{APPLICATION_JSON, IDEMPOTENCY_KEY},
+    create_postcard::request::Recipient,
+};
+
+pub struct CreateSelfMailerRequest<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+    'p,
+    Merge,
+> {
+    pub(crate) client: reqwest::Client,
+    pub(crate) api_key: &'a str,
+    pub(crate) url: Cow<'b, str>,
+    pub(crate) idempotency_key: &'c str,
+    pub(crate) json_request: JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge>,
+}
+
+pub struct CreateSelfMailerRequestNoMerge<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+    'p,
+> {
+    pub(crate) client: reqwest::Client,
+    pub(crate) api_key: &'a str,
+    pub(crate) url: Cow<'b, str>,
+    pub(crate) idempotency_key: &'c str,
+    pub(crate) json_request: JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>,
+}
+
+#[derive(Serialize)]
+pub struct JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    pub(crate) inside: &'j str,
+    pub(crate) outside: &'k str,
+    pub(crate) size: SelfMailerSize,
+    pub(crate) mail_type: MailType,
+    pub(crate) merge_variables: Merge,
+    pub(crate) description: &'l str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) from: Option<&'o AddressId>,
+    pub(crate) use_type: UseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'m>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'n str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'p BillingGroupId>,
+}
+
+#[derive(Serialize)]
+pub struct JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    pub(crate) inside: &'j str,
+    pub(crate) outside: &'k str,
+    pub(crate) size: SelfMailerSize,
+    pub(crate) mail_type: MailType,
+    pub(crate) description: &'l str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) from: Option<&'o AddressId>,
+    pub(crate) use_type: UseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'m>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'n str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'p BillingGroupId>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Specifies the size of the self mailer. All self mailers are bifold.
+pub enum SelfMailerSize {
+    #[serde(rename = "6x18_bifold")]
+    SixByEighteenBifold,
+    #[serde(rename = "11x9_bifold")]
+    ElevenByNineBifold,
+    #[serde(rename = "12x9_bifold")]
+    TwelveByNineBifold,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge: Serialize>
+    CreateSelfMailerRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge>
+{
+    pub async fn send(self) -> Result<SelfMailer, SelfMailerError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .header(IDEMPOTENCY_KEY, self.idempotency_key)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+    CreateSelfMailerRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+{
+    pub async fn send(self) -> Result<SelfMailer, SelfMailerError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .header(IDEMPOTENCY_KEY, self.idempotency_key)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
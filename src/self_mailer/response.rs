@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{Address, MailType, SelfMailerSize, TrackingEvent, UseType, id::lob_id};
+
+lob_id!(
+    /// The id of a self mailer, prefixed with `sfm_`
+    SelfMailerId
+);
+
+/// A self mailer as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct SelfMailer {
+    /// the id of the self mailer
+    pub id: SelfMailerId,
+    /// the description of the self mailer
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the recipient of the self mailer
+    pub to: Address,
+    /// the sender of the self mailer
+    #[serde(default)]
+    pub from: Option<Address>,
+    /// a signed link to the rendered self mailer
+    #[serde(default)]
+    pub url: Option<Box<str>>,
+    /// the size of the self mailer
+    pub size: SelfMailerSize,
+    /// the postage type of the self mailer
+    pub mail_type: MailType,
+    /// the use type of the self mailer
+    #[serde(default)]
+    pub use_type: Option<UseType>,
+    /// the metadata attached to the self mailer
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// the send date
+    pub send_date: Box<str>,
+    /// the expected delivery date
+    #[serde(default)]
+    pub expected_delivery_date: Option<Box<str>>,
+    /// when the self mailer was created
+    pub date_created: Box<str>,
+    /// when the self mailer was last modified
+    pub date_modified: Box<str>,
+    /// the usps scans of the self mailer so far, oldest first
+    #[serde(default)]
+    pub tracking_events: Vec<TrackingEvent>,
+    /// whether the self mailer has been cancelled
+    #[serde(default)]
+    pub deleted: bool,
+}
@@ -0,0 +1,627 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::{
+    AddressId, BillingGroupId, MailType, Metadata, UseType,
+    create_postcard::request::{Recipient, To},
+};
+
+use super::request::{
+    CreateSnapPackRequest, CreateSnapPackRequestNoMerge, JsonRequest, JsonRequestNoMerge,
+    SnapPackSize,
+};
+
+/// Builder for creating a create snap pack request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateSnapPackBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateSnapPackBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the idempotency key for the request. Idempotent requests are requests that can be called many times without producing different outcomes.
+    ///
+    /// You can safely retry the same request with the same Idempotency Key and be assured that no duplicate snap packs are created even if the API is called multiple times within 24 hours.
+    pub fn idempotency_key<'c>(
+        self,
+        idempotency_key: &'c str,
+    ) -> CreateSnapPackBuilderWithIdempotencyKey<'a, 'b, 'c> {
+        CreateSnapPackBuilderWithIdempotencyKey {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with an idempotency key set.
+pub struct CreateSnapPackBuilderWithIdempotencyKey<'a, 'b, 'c> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+}
+
+impl<'a, 'b, 'c> CreateSnapPackBuilderWithIdempotencyKey<'a, 'b, 'c> {
+    /// sets the name for the address to which the snap pack is being sent
+    pub fn name<'d>(self, name: &'d str) -> CreateSnapPackBuilderWithName<'a, 'b, 'c, 'd> {
+        CreateSnapPackBuilderWithName {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name,
+        }
+    }
+
+    /// sends the snap pack to an address saved to your lob address book
+    pub fn to<'d, 'e, 'f, 'g, 'h, 'i>(
+        self,
+        to: &'d AddressId,
+    ) -> CreateSnapPackBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreateSnapPackBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Id(to),
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a name set.
+pub struct CreateSnapPackBuilderWithName<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+}
+
+impl<'a, 'b, 'c, 'd> CreateSnapPackBuilderWithName<'a, 'b, 'c, 'd> {
+    /// sets the first address line for the address to which the snap pack is being sent
+    pub fn address_line_1<'e, 'f>(
+        self,
+        address_line_1: &'e str,
+    ) -> CreateSnapPackBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+        CreateSnapPackBuilderWithAddress {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1,
+            address_line_2: None,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with addresses set.
+pub struct CreateSnapPackBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> CreateSnapPackBuilderWithAddress<'a, 'b, 'c, 'd, 'e, 'f> {
+    /// sets the second address line for the address to which the snap pack is being sent
+    pub fn address_line_2(self, address_line_2: &'f str) -> Self {
+        Self {
+            address_line_2: Some(address_line_2),
+            ..self
+        }
+    }
+
+    /// sets the city for the address to which the snap pack is being sent
+    pub fn city<'g>(
+        self,
+        city: &'g str,
+    ) -> CreateSnapPackBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+        CreateSnapPackBuilderWithCity {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a city set.
+pub struct CreateSnapPackBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+    city: &'g str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> CreateSnapPackBuilderWithCity<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
+    /// sets the state for the address to which the snap pack is being sent
+    pub fn state<'h>(
+        self,
+        state: &'h str,
+    ) -> CreateSnapPackBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+        CreateSnapPackBuilderWithState {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            name: self.name,
+            address_line_1: self.address_line_1,
+            address_line_2: self.address_line_2,
+            city: self.city,
+            state,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a state set.
+pub struct CreateSnapPackBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    name: &'d str,
+    address_line_1: &'e str,
+    address_line_2: Option<&'f str>,
+    city: &'g str,
+    state: &'h str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+    CreateSnapPackBuilderWithState<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h>
+{
+    /// sets the zip code for the address to which the snap pack is being sent
+    pub fn zip_code<'i>(
+        self,
+        zip_code: &'i str,
+    ) -> CreateSnapPackBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        CreateSnapPackBuilderWithZipCode {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: Recipient::Inline(To {
+                name: self.name,
+                address_line_1: self.address_line_1,
+                address_line_2: self.address_line_2,
+                address_city: self.city,
+                address_state: self.state,
+                address_zip: zip_code,
+                address_country: None,
+            }),
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a zip code set.
+pub struct CreateSnapPackBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+    CreateSnapPackBuilderWithZipCode<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+{
+    /// sets the return address of the snap pack, as the id of an address saved to your lob address book (`adr_...`)
+    pub fn from<'j>(
+        self,
+        from: &'j AddressId,
+    ) -> CreateSnapPackBuilderWithFrom<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+        CreateSnapPackBuilderWithFrom {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a return address set.
+pub struct CreateSnapPackBuilderWithFrom<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+    CreateSnapPackBuilderWithFrom<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+{
+    /// sets the inside template of the snap pack being sent.
+    ///
+    /// The artwork to use as the inside of your snap pack.
+    ///
+    /// ## Notes:
+    ///
+    /// HTML merge variables should not include delimiting whitespace.
+    /// PDF, PNG, and JPGs must be sized at 8.5"x11" at 300 DPI, while supplied HTML will be rendered to the specified size.
+    pub fn inside<'k>(
+        self,
+        inside: &'k str,
+    ) -> CreateSnapPackBuilderWithInside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+        CreateSnapPackBuilderWithInside {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            inside,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with inside template set.
+pub struct CreateSnapPackBuilderWithInside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    inside: &'k str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+    CreateSnapPackBuilderWithInside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
+{
+    /// sets the outside template of the snap pack being sent.
+    ///
+    /// The artwork to use as the outside of your snap pack.
+    ///
+    /// ## Notes:
+    ///
+    /// HTML merge variables should not include delimiting whitespace.
+    /// PDF, PNG, and JPGs must be sized at 8.5"x11" at 300 DPI, while supplied HTML will be rendered to the specified size.
+    pub fn outside<'l>(
+        self,
+        outside: &'l str,
+    ) -> CreateSnapPackBuilderWithOutside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+        CreateSnapPackBuilderWithOutside {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            inside: self.inside,
+            outside,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with outside template set.
+pub struct CreateSnapPackBuilderWithOutside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    inside: &'k str,
+    outside: &'l str,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+    CreateSnapPackBuilderWithOutside<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+{
+    /// sets the "usage type" for the snap pack being sent.
+    ///
+    /// The use type for each mailpiece. Can be one of marketing, operational. For more information on use_type, see lob's Help Center article.
+    pub fn use_type(
+        self,
+        use_type: UseType,
+    ) -> CreateSnapPackBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+        CreateSnapPackBuilderWithUseType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            inside: self.inside,
+            outside: self.outside,
+            use_type,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a use type set.
+pub struct CreateSnapPackBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    inside: &'k str,
+    outside: &'l str,
+    use_type: UseType,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+    CreateSnapPackBuilderWithUseType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+{
+    /// sets the "size" for the snap pack being sent.
+    pub fn size(
+        self,
+        size: SnapPackSize,
+    ) -> CreateSnapPackBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+        CreateSnapPackBuilderWithSize {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            inside: self.inside,
+            outside: self.outside,
+            use_type: self.use_type,
+            size,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a snap pack size set.
+pub struct CreateSnapPackBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    inside: &'k str,
+    outside: &'l str,
+    use_type: UseType,
+    size: SnapPackSize,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+    CreateSnapPackBuilderWithSize<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+{
+    /// sets the "mail type" for the snap pack being sent.
+    pub fn mail_type(
+        self,
+        mail_type: MailType,
+    ) -> CreateSnapPackBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+        CreateSnapPackBuilderWithMailType {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            inside: self.inside,
+            outside: self.outside,
+            use_type: self.use_type,
+            size: self.size,
+            mail_type,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a mail type set.
+pub struct CreateSnapPackBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    inside: &'k str,
+    outside: &'l str,
+    use_type: UseType,
+    size: SnapPackSize,
+    mail_type: MailType,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+    CreateSnapPackBuilderWithMailType<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l>
+{
+    /// sets the description of the snap pack being sent.
+    pub fn description<'m, 'n, 'o, 'p>(
+        self,
+        description: &'m str,
+    ) -> CreateSnapPackBuilderWithDescription<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+        'p,
+    > {
+        CreateSnapPackBuilderWithDescription {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            to: self.to,
+            from: self.from,
+            inside: self.inside,
+            outside: self.outside,
+            use_type: self.use_type,
+            size: self.size,
+            mail_type: self.mail_type,
+            description,
+            metadata: None,
+            send_date: None,
+            billing_group_id: None,
+        }
+    }
+}
+
+/// Builder for a create snap pack request with a description set.
+pub struct CreateSnapPackBuilderWithDescription<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+    'p,
+> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    idempotency_key: &'c str,
+    to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    from: &'j AddressId,
+    inside: &'k str,
+    outside: &'l str,
+    use_type: UseType,
+    size: SnapPackSize,
+    mail_type: MailType,
+    description: &'m str,
+    metadata: Option<Metadata<'n>>,
+    send_date: Option<&'o str>,
+    billing_group_id: Option<&'p BillingGroupId>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+    CreateSnapPackBuilderWithDescription<
+        'a,
+        'b,
+        'c,
+        'd,
+        'e,
+        'f,
+        'g,
+        'h,
+        'i,
+        'j,
+        'k,
+        'l,
+        'm,
+        'n,
+        'o,
+        'p,
+    >
+{
+    /// sets the metadata attached to the snap pack being sent.
+    pub fn metadata(self, metadata: Metadata<'n>) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    /// sets the date the snap pack will be sent.
+    ///
+    /// A timestamp in ISO 8601 format which specifies a date after the current time and up to 180 days in the future to send the snap pack off for production. Until the send date, the snap pack can be cancelled.
+    pub fn send_date(self, send_date: &'o str) -> Self {
+        Self {
+            send_date: Some(send_date),
+            ..self
+        }
+    }
+
+    /// bills the snap pack to one of your billing groups
+    pub fn billing_group_id(self, billing_group_id: &'p BillingGroupId) -> Self {
+        Self {
+            billing_group_id: Some(billing_group_id),
+            ..self
+        }
+    }
+
+    pub fn build(
+        self,
+    ) -> CreateSnapPackRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+    {
+        CreateSnapPackRequestNoMerge {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            json_request: JsonRequestNoMerge {
+                to: self.to,
+                from: self.from,
+                inside: self.inside,
+                outside: self.outside,
+                use_type: self.use_type,
+                size: self.size,
+                mail_type: self.mail_type,
+                description: self.description,
+                metadata: self.metadata,
+                send_date: self.send_date,
+                billing_group_id: self.billing_group_id,
+            },
+        }
+    }
+
+    pub fn merge<Merge: Serialize>(
+        self,
+        merge_variables: Merge,
+    ) -> CreateSnapPackRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge>
+    {
+        CreateSnapPackRequest {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            idempotency_key: self.idempotency_key,
+            json_request: JsonRequest {
+                to: self.to,
+                from: self.from,
+                inside: self.inside,
+                outside: self.outside,
+                use_type: self.use_type,
+                size: self.size,
+                mail_type: self.mail_type,
+                description: self.description,
+                metadata: self.metadata,
+                send_date: self.send_date,
+                billing_group_id: self.billing_group_id,
+                merge_variables,
+            },
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub(crate) mod builder;
+mod error;
+mod request;
+mod response;
+
+pub use error::SnapPackError;
+pub use request::SnapPackSize;
+pub use response::{SnapPack, SnapPackId};
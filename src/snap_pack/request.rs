@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AddressId, BillingGroupId, MailType, Metadata, SnapPack, SnapPackError, UseType,
+    client::send::send,
+    constants::{APPLICATION_JSON, IDEMPOTENCY_KEY},
+    create_postcard::request::Recipient,
+};
+
+pub struct CreateSnapPackRequest<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+    'p,
+    Merge,
+> {
+    pub(crate) client: reqwest::Client,
+    pub(crate) api_key: &'a str,
+    pub(crate) url: Cow<'b, str>,
+    pub(crate) idempotency_key: &'c str,
+    pub(crate) json_request: JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge>,
+}
+
+pub struct CreateSnapPackRequestNoMerge<
+    'a,
+    'b,
+    'c,
+    'd,
+    'e,
+    'f,
+    'g,
+    'h,
+    'i,
+    'j,
+    'k,
+    'l,
+    'm,
+    'n,
+    'o,
+    'p,
+> {
+    pub(crate) client: reqwest::Client,
+    pub(crate) api_key: &'a str,
+    pub(crate) url: Cow<'b, str>,
+    pub(crate) idempotency_key: &'c str,
+    pub(crate) json_request: JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>,
+}
+
+#[derive(Serialize)]
+pub struct JsonRequest<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    pub(crate) from: &'j AddressId,
+    pub(crate) inside: &'k str,
+    pub(crate) outside: &'l str,
+    pub(crate) size: SnapPackSize,
+    pub(crate) mail_type: MailType,
+    pub(crate) merge_variables: Merge,
+    pub(crate) description: &'m str,
+    pub(crate) use_type: UseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'n>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'o str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'p BillingGroupId>,
+}
+
+#[derive(Serialize)]
+pub struct JsonRequestNoMerge<'d, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p> {
+    pub(crate) to: Recipient<'d, 'e, 'f, 'g, 'h, 'i>,
+    pub(crate) from: &'j AddressId,
+    pub(crate) inside: &'k str,
+    pub(crate) outside: &'l str,
+    pub(crate) size: SnapPackSize,
+    pub(crate) mail_type: MailType,
+    pub(crate) description: &'m str,
+    pub(crate) use_type: UseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'n>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_date: Option<&'o str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) billing_group_id: Option<&'p BillingGroupId>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Specifies the size of the snap pack. Snap packs are pressure sealed, so their contents stay hidden until opened.
+pub enum SnapPackSize {
+    #[serde(rename = "8.5x11")]
+    EightAndHalfByEleven,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge: Serialize>
+    CreateSnapPackRequest<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p, Merge>
+{
+    pub async fn send(self) -> Result<SnapPack, SnapPackError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .header(IDEMPOTENCY_KEY, self.idempotency_key)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+    CreateSnapPackRequestNoMerge<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k, 'l, 'm, 'n, 'o, 'p>
+{
+    pub async fn send(self) -> Result<SnapPack, SnapPackError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .header(IDEMPOTENCY_KEY, self.idempotency_key)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
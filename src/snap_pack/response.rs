@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{Address, MailType, SnapPackSize, TrackingEvent, UseType, id::lob_id};
+
+lob_id!(
+    /// The id of a snap pack, prefixed with `ord_`
+    SnapPackId
+);
+
+/// A snap pack as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct SnapPack {
+    /// the id of the snap pack
+    pub id: SnapPackId,
+    /// the description of the snap pack
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the recipient of the snap pack
+    pub to: Address,
+    /// the sender of the snap pack
+    pub from: Address,
+    /// a signed link to the rendered snap pack
+    #[serde(default)]
+    pub url: Option<Box<str>>,
+    /// the size of the snap pack
+    pub size: SnapPackSize,
+    /// the postage type of the snap pack
+    pub mail_type: MailType,
+    /// the use type of the snap pack
+    #[serde(default)]
+    pub use_type: Option<UseType>,
+    /// the metadata attached to the snap pack
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// the send date
+    pub send_date: Box<str>,
+    /// the expected delivery date
+    #[serde(default)]
+    pub expected_delivery_date: Option<Box<str>>,
+    /// when the snap pack was created
+    pub date_created: Box<str>,
+    /// when the snap pack was last modified
+    pub date_modified: Box<str>,
+    /// the usps scans of the snap pack so far, oldest first
+    #[serde(default)]
+    pub tracking_events: Vec<TrackingEvent>,
+    /// whether the snap pack has been cancelled
+    #[serde(default)]
+    pub deleted: bool,
+}
@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+
+use crate::{
+    Metadata, Template, TemplateEngine, TemplateError, TemplateVersion, TemplateVersionId,
+    client::send::send,
+    constants::APPLICATION_JSON,
+    template::request::{CreateTemplateJson, CreateTemplateVersionJson, UpdateTemplateJson},
+};
+use reqwest::header::CONTENT_TYPE;
+
+/// Builder for a create template request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateTemplateBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateTemplateBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the html of the template's first version
+    pub fn html<'c, 'd, 'e>(
+        self,
+        html: &'c str,
+    ) -> CreateTemplateBuilderWithHtml<'a, 'b, 'c, 'd, 'e> {
+        CreateTemplateBuilderWithHtml {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: CreateTemplateJson {
+                html,
+                description: None,
+                engine: None,
+                metadata: None,
+            },
+        }
+    }
+}
+
+/// Builder for a create template request with html set.
+pub struct CreateTemplateBuilderWithHtml<'a, 'b, 'c, 'd, 'e> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: CreateTemplateJson<'c, 'd, 'e>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e> CreateTemplateBuilderWithHtml<'a, 'b, 'c, 'd, 'e> {
+    /// sets the description of the template
+    pub fn description(mut self, description: &'d str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sets the engine the html is rendered with
+    pub fn engine(mut self, engine: TemplateEngine) -> Self {
+        self.json_request.engine = Some(engine);
+        self
+    }
+
+    /// sets the metadata attached to the template
+    pub fn metadata(mut self, metadata: Metadata<'e>) -> Self {
+        self.json_request.metadata = Some(metadata);
+        self
+    }
+
+    /// sends the create template request
+    pub async fn send(self) -> Result<Template, TemplateError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+/// Builder for an update template request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct UpdateTemplateBuilder<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: UpdateTemplateJson<'c, 'd>,
+}
+
+impl<'a, 'b, 'c, 'd> UpdateTemplateBuilder<'a, 'b, 'c, 'd> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+            json_request: UpdateTemplateJson {
+                description: None,
+                published_version: None,
+            },
+        }
+    }
+
+    /// changes the description of the template
+    pub fn description(mut self, description: &'c str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// changes which version is used when the template is mailed
+    pub fn published_version(mut self, published_version: &'d TemplateVersionId) -> Self {
+        self.json_request.published_version = Some(published_version);
+        self
+    }
+
+    /// sends the update template request
+    pub async fn send(self) -> Result<Template, TemplateError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
+
+/// Builder for a create template version request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct CreateTemplateVersionBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> CreateTemplateVersionBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the html of the new version
+    pub fn html<'c, 'd>(
+        self,
+        html: &'c str,
+    ) -> CreateTemplateVersionBuilderWithHtml<'a, 'b, 'c, 'd> {
+        CreateTemplateVersionBuilderWithHtml {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: CreateTemplateVersionJson {
+                html,
+                description: None,
+                engine: None,
+            },
+        }
+    }
+}
+
+/// Builder for a create template version request with html set.
+pub struct CreateTemplateVersionBuilderWithHtml<'a, 'b, 'c, 'd> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: CreateTemplateVersionJson<'c, 'd>,
+}
+
+impl<'a, 'b, 'c, 'd> CreateTemplateVersionBuilderWithHtml<'a, 'b, 'c, 'd> {
+    /// sets the description of the version
+    pub fn description(mut self, description: &'d str) -> Self {
+        self.json_request.description = Some(description);
+        self
+    }
+
+    /// sets the engine the html is rendered with
+    pub fn engine(mut self, engine: TemplateEngine) -> Self {
+        self.json_request.engine = Some(engine);
+        self
+    }
+
+    /// sends the create template version request
+    ///
+    /// The new version is not published until it is set with [`UpdateTemplateBuilder::published_version`].
+    pub async fn send(self) -> Result<TemplateVersion, TemplateError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
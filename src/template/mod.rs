@@ -0,0 +1,10 @@
+pub(crate) mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::TemplateError;
+pub use response::{
+    Template, TemplateEngine, TemplateId, TemplateMergeVariables, TemplateVersion,
+    TemplateVersionId,
+};
@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+use crate::{Metadata, TemplateEngine, TemplateVersionId};
+
+#[derive(Serialize)]
+pub(crate) struct CreateTemplateJson<'c, 'd, 'e> {
+    pub(crate) html: &'c str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) engine: Option<TemplateEngine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<Metadata<'e>>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UpdateTemplateJson<'c, 'd> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'c str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) published_version: Option<&'d TemplateVersionId>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct CreateTemplateVersionJson<'c, 'd> {
+    pub(crate) html: &'c str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) engine: Option<TemplateEngine>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UpdateTemplateVersionJson<'c> {
+    pub(crate) description: &'c str,
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::id::lob_id;
+
+lob_id!(
+    /// The id of a template, prefixed with `tmpl_`
+    ///
+    /// Can be passed as the artwork of any mail piece builder in this crate with [`as_str`](TemplateId::as_str).
+    TemplateId
+);
+
+lob_id!(
+    /// The id of a template version, prefixed with `vrsn_`
+    TemplateVersionId
+);
+
+/// A template as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Template {
+    /// the id of the template
+    pub id: TemplateId,
+    /// the description of the template
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// every version of the template
+    #[serde(default)]
+    pub versions: Vec<TemplateVersion>,
+    /// the version used when the template is mailed
+    pub published_version: TemplateVersion,
+    /// the metadata attached to the template
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// when the template was created
+    pub date_created: Box<str>,
+    /// when the template was last modified
+    pub date_modified: Box<str>,
+}
+
+/// A version of a template's html
+#[derive(Clone, Debug, Deserialize)]
+pub struct TemplateVersion {
+    /// the id of the version
+    pub id: TemplateVersionId,
+    /// the description of the version
+    #[serde(default)]
+    pub description: Option<Box<str>>,
+    /// the html of the version
+    pub html: Box<str>,
+    /// the engine the html is rendered with
+    #[serde(default)]
+    pub engine: Option<TemplateEngine>,
+    /// the merge variables lob found in the html
+    #[serde(default)]
+    pub merge_variables: TemplateMergeVariables,
+    /// when the version was created
+    pub date_created: Box<str>,
+    /// when the version was last modified
+    pub date_modified: Box<str>,
+}
+
+/// The merge variables lob extracted from a template version
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct TemplateMergeVariables {
+    /// the name of every merge variable used in the html
+    #[serde(default)]
+    pub keys: Vec<Box<str>>,
+}
+
+/// The engine a template's html is rendered with
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateEngine {
+    /// lob's original engine, supporting only simple `{{variable}}` substitution (default)
+    Legacy,
+    /// handlebars, supporting conditionals and loops
+    Handlebars,
+}
@@ -82,3 +82,2545 @@ async fn test() {
 
     println!("{response:#?}");
 }
+
+/// Serves each response to one connection in turn, sending back the request line of each.
+///
+/// Returns the base url to build a [`LobClient`] with.
+fn serve(responses: Vec<(u16, String)>) -> (String, std::sync::mpsc::Receiver<String>) {
+    serve_map(responses, |request_line, _| request_line)
+}
+
+/// like [`serve`], also recording the body of each request
+fn serve_with_bodies(
+    responses: Vec<(u16, String)>,
+) -> (String, std::sync::mpsc::Receiver<(String, String)>) {
+    serve_map(responses, |request_line, body| (request_line, body))
+}
+
+fn serve_map<T: Send + 'static>(
+    responses: Vec<(u16, String)>,
+    map: fn(String, String) -> T,
+) -> (String, std::sync::mpsc::Receiver<T>) {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/v1/", listener.local_addr().unwrap());
+    let (requests, received) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            let mut header = String::new();
+            while reader.read_line(&mut header).unwrap() > 2 {
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+                header.clear();
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            let request_body = String::from_utf8_lossy(&request_body).into_owned();
+            let _ = requests.send(map(request_line.trim_end().to_owned(), request_body));
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, received)
+}
+
+#[test]
+fn us_verification_response() {
+    use crate::{Deliverability, DpvConfirmation, UsVerification};
+
+    const SRC: &str = r#"{
+        "id": "us_ver_c7cb63d68f8d6",
+        "recipient": "LOB.COM",
+        "primary_line": "185 BERRY ST STE 6100",
+        "secondary_line": "",
+        "urbanization": "",
+        "last_line": "SAN FRANCISCO CA 94107-1728",
+        "deliverability": "deliverable",
+        "components": {
+            "primary_number": "185",
+            "street_predirection": "",
+            "street_name": "BERRY",
+            "street_suffix": "ST",
+            "street_postdirection": "",
+            "secondary_designator": "STE",
+            "secondary_number": "6100",
+            "city": "SAN FRANCISCO",
+            "state": "CA",
+            "zip_code": "94107",
+            "zip_code_plus_4": "1728",
+            "county": "SAN FRANCISCO",
+            "county_fips": "06075",
+            "carrier_route": "C001",
+            "address_type": "commercial",
+            "latitude": 37.77597,
+            "longitude": -122.39332
+        },
+        "deliverability_analysis": {
+            "dpv_confirmation": "Y",
+            "dpv_cmra": "N",
+            "dpv_vacant": "",
+            "dpv_active": "Y",
+            "dpv_footnotes": ["AA", "BB"],
+            "ews_match": false,
+            "lacs_indicator": "",
+            "lacs_return_code": "",
+            "suite_return_code": ""
+        },
+        "object": "us_verification"
+    }"#;
+
+    let verification: UsVerification = serde_json::from_str(SRC).unwrap();
+
+    assert_eq!(verification.deliverability, Deliverability::Deliverable);
+    let analysis = &verification.deliverability_analysis;
+    assert_eq!(analysis.dpv_confirmation, DpvConfirmation::Confirmed);
+    assert_eq!(analysis.dpv_cmra, Some(false));
+    assert_eq!(analysis.dpv_vacant, None);
+    assert_eq!(analysis.dpv_active, Some(true));
+}
+
+#[test]
+fn bulk_us_verification_response() {
+    use crate::{ApiError, UsVerification, bulk::BulkResponse};
+
+    const SRC: &str = r#"{
+        "addresses": [
+            {
+                "error": {
+                    "message": "primary_line is required or address is required",
+                    "status_code": 422
+                }
+            },
+            {
+                "primary_line": "185 BERRY ST STE 6100",
+                "last_line": "SAN FRANCISCO CA 94107-1728",
+                "deliverability": "deliverable",
+                "components": { "city": "SAN FRANCISCO", "state": "CA", "zip_code": "94107" },
+                "deliverability_analysis": { "dpv_confirmation": "Y", "dpv_cmra": "N", "dpv_vacant": "N" }
+            }
+        ]
+    }"#;
+
+    let response: BulkResponse<UsVerification> = serde_json::from_str(SRC).unwrap();
+    let results: Vec<Result<UsVerification, ApiError>> =
+        response.addresses.into_iter().map(Into::into).collect();
+
+    assert_eq!(results[0].as_ref().unwrap_err().status_code, 422);
+    assert_eq!(
+        &*results[1].as_ref().unwrap().primary_line,
+        "185 BERRY ST STE 6100"
+    );
+}
+
+#[tokio::test]
+async fn bulk_us_verification_batch_failure() {
+    use crate::{US_VERIFICATION_BATCH_LIMIT, UsVerificationAddress, UsVerificationError};
+
+    let verified = serde_json::json!({
+        "primary_line": "185 BERRY ST STE 6100",
+        "last_line": "SAN FRANCISCO CA 94107-1728",
+        "deliverability": "deliverable",
+        "components": { "city": "SAN FRANCISCO", "state": "CA", "zip_code": "94107" },
+        "deliverability_analysis": { "dpv_confirmation": "Y", "dpv_cmra": "N", "dpv_vacant": "N" }
+    });
+    let first_batch =
+        serde_json::json!({ "addresses": vec![verified; US_VERIFICATION_BATCH_LIMIT] });
+    let rate_limited = serde_json::json!({
+        "error": { "message": "rate limit exceeded", "status_code": 429 }
+    });
+
+    let (base_url, _) = serve(vec![
+        (200, first_batch.to_string()),
+        (429, rate_limited.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let address = UsVerificationAddress {
+        primary_line: "185 Berry St Ste 6100",
+        zip_code: Some("94107"),
+        ..Default::default()
+    };
+    let addresses = vec![address; US_VERIFICATION_BATCH_LIMIT + 1];
+
+    match lob_client.verify_us_addresses(&addresses).await {
+        Err(UsVerificationError::Batch { completed, source }) => {
+            assert_eq!(completed.len(), US_VERIFICATION_BATCH_LIMIT);
+            assert!(completed.iter().all(Result::is_ok));
+            assert!(matches!(*source, UsVerificationError::Api(ref err) if err.status_code == 429));
+        }
+        other => panic!("expected the second batch to fail, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn bulk_us_verification_length_mismatch() {
+    use crate::{UsVerificationAddress, UsVerificationError};
+
+    let verified = serde_json::json!({
+        "primary_line": "185 BERRY ST STE 6100",
+        "last_line": "SAN FRANCISCO CA 94107-1728",
+        "deliverability": "deliverable",
+        "components": { "city": "SAN FRANCISCO", "state": "CA", "zip_code": "94107" },
+        "deliverability_analysis": { "dpv_confirmation": "Y", "dpv_cmra": "N", "dpv_vacant": "N" }
+    });
+    let short = serde_json::json!({ "addresses": [verified] });
+
+    let (base_url, _) = serve(vec![(200, short.to_string())]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let address = UsVerificationAddress {
+        primary_line: "185 Berry St Ste 6100",
+        zip_code: Some("94107"),
+        ..Default::default()
+    };
+
+    match lob_client.verify_us_addresses(&[address, address]).await {
+        Err(UsVerificationError::Batch { completed, source }) => {
+            assert!(completed.is_empty());
+            assert!(matches!(
+                *source,
+                UsVerificationError::BatchLength {
+                    sent: 2,
+                    received: 1
+                }
+            ));
+        }
+        other => panic!("expected a batch length error, got {other:?}"),
+    }
+}
+
+fn intl_verification_json(primary_line: &str, deliverability: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": "intl_ver_5f2b3e3c7b1d8a9",
+        "primary_line": primary_line,
+        "last_line": "TORONTO ON M5J 2N1",
+        "country": "CA",
+        "coverage": "SUBBUILDING",
+        "deliverability": deliverability,
+        "status": "LV4",
+        "components": {
+            "primary_number": "370",
+            "street_name": "WATER ST",
+            "city": "TORONTO",
+            "state": "ON",
+            "postal_code": "M5J 2N1"
+        },
+        "object": "intl_verification"
+    })
+}
+
+#[test]
+fn intl_verification() {
+    use crate::{ApiError, IntlDeliverability, IntlVerification, bulk::BulkResponse};
+
+    let verification: IntlVerification =
+        serde_json::from_value(intl_verification_json("370 WATER ST", "deliverable")).unwrap();
+    assert_eq!(verification.country.as_str(), "CA");
+    assert_eq!(verification.deliverability, IntlDeliverability::Deliverable);
+    assert_eq!(&*verification.components.postal_code, "M5J 2N1");
+
+    let deliverabilities: Vec<IntlDeliverability> = serde_json::from_str(
+        r#"["deliverable", "deliverable_missing_info", "undeliverable", "no_match"]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        deliverabilities,
+        [
+            IntlDeliverability::Deliverable,
+            IntlDeliverability::DeliverableMissingInfo,
+            IntlDeliverability::Undeliverable,
+            IntlDeliverability::NoMatch
+        ]
+    );
+
+    let response = serde_json::json!({
+        "addresses": [
+            { "error": { "message": "country is required", "status_code": 422 } },
+            intl_verification_json("370 WATER ST", "no_match")
+        ]
+    });
+    let response: BulkResponse<IntlVerification> = serde_json::from_value(response).unwrap();
+    let results: Vec<Result<IntlVerification, ApiError>> =
+        response.addresses.into_iter().map(Into::into).collect();
+
+    assert_eq!(results[0].as_ref().unwrap_err().status_code, 422);
+    assert_eq!(
+        results[1].as_ref().unwrap().deliverability,
+        IntlDeliverability::NoMatch
+    );
+}
+
+#[tokio::test]
+async fn bulk_intl_verification_batches() {
+    use crate::{CountryCode, INTL_VERIFICATION_BATCH_LIMIT, IntlVerificationAddress};
+
+    let verified = intl_verification_json("370 WATER ST", "deliverable");
+    let first_batch =
+        serde_json::json!({ "addresses": vec![verified.clone(); INTL_VERIFICATION_BATCH_LIMIT] });
+    let second_batch = serde_json::json!({ "addresses": [verified] });
+
+    let (base_url, requests) = serve_with_bodies(vec![
+        (200, first_batch.to_string()),
+        (200, second_batch.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let address = IntlVerificationAddress::new("370 Water St", CountryCode::new("CA").unwrap());
+    let addresses = vec![address; INTL_VERIFICATION_BATCH_LIMIT + 1];
+
+    let results = lob_client.verify_intl_addresses(&addresses).await.unwrap();
+    assert_eq!(results.len(), INTL_VERIFICATION_BATCH_LIMIT + 1);
+    assert!(results.iter().all(Result::is_ok));
+
+    let requests: Vec<(String, serde_json::Value)> = requests
+        .try_iter()
+        .map(|(request_line, body)| (request_line, serde_json::from_str(&body).unwrap()))
+        .collect();
+    assert_eq!(requests.len(), 2);
+    for (request_line, _) in &requests {
+        assert_eq!(request_line, "POST /v1/bulk/intl_verifications HTTP/1.1");
+    }
+    assert_eq!(
+        requests[0].1["addresses"].as_array().unwrap().len(),
+        INTL_VERIFICATION_BATCH_LIMIT
+    );
+    assert_eq!(
+        requests[1].1,
+        serde_json::json!({ "addresses": [{ "primary_line": "370 Water St", "country": "CA" }] })
+    );
+}
+
+#[tokio::test]
+async fn bulk_intl_verification_batch_failure() {
+    use crate::{
+        CountryCode, INTL_VERIFICATION_BATCH_LIMIT, IntlVerificationAddress, IntlVerificationError,
+    };
+
+    let verified = intl_verification_json("370 WATER ST", "deliverable");
+    let first_batch =
+        serde_json::json!({ "addresses": vec![verified.clone(); INTL_VERIFICATION_BATCH_LIMIT] });
+    let rate_limited = serde_json::json!({
+        "error": { "message": "rate limit exceeded", "status_code": 429 }
+    });
+    let short = serde_json::json!({ "addresses": [verified] });
+
+    let (base_url, _) = serve(vec![
+        (200, first_batch.to_string()),
+        (429, rate_limited.to_string()),
+        (200, short.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let address = IntlVerificationAddress::new("370 Water St", CountryCode::new("CA").unwrap());
+    let addresses = vec![address; INTL_VERIFICATION_BATCH_LIMIT + 1];
+
+    match lob_client.verify_intl_addresses(&addresses).await {
+        Err(IntlVerificationError::Batch { completed, source }) => {
+            assert_eq!(completed.len(), INTL_VERIFICATION_BATCH_LIMIT);
+            assert!(completed.iter().all(Result::is_ok));
+            assert!(
+                matches!(*source, IntlVerificationError::Api(ref err) if err.status_code == 429)
+            );
+        }
+        other => panic!("expected the second batch to fail, got {other:?}"),
+    }
+
+    match lob_client.verify_intl_addresses(&[address, address]).await {
+        Err(IntlVerificationError::Batch { completed, source }) => {
+            assert!(completed.is_empty());
+            assert!(matches!(
+                *source,
+                IntlVerificationError::BatchLength {
+                    sent: 2,
+                    received: 1
+                }
+            ));
+        }
+        other => panic!("expected a batch length error, got {other:?}"),
+    }
+}
+
+#[test]
+fn country_code() {
+    use crate::CountryCode;
+
+    assert_eq!(CountryCode::new("mx").unwrap().as_str(), "MX");
+    assert!(CountryCode::new("MEX").is_none());
+    assert!(CountryCode::new("M1").is_none());
+}
+
+#[test]
+fn bare_array_list() {
+    use crate::List;
+
+    let list: List<u8> = serde_json::from_str("[1, 2, 3]").unwrap();
+
+    assert_eq!(list.data, [1, 2, 3]);
+    assert_eq!(list.count, 3);
+    assert_eq!(list.next_cursor(), None);
+    assert!(serde_json::from_str::<List<u8>>(r#"{ "data": ["x"], "count": 1 }"#).is_err());
+}
+
+#[test]
+fn upload_state() {
+    use crate::UploadState;
+
+    let states: Vec<UploadState> =
+        serde_json::from_str(r#"["Ready for Validation", "Draft", "Archived"]"#).unwrap();
+
+    assert_eq!(
+        states,
+        [
+            UploadState::ReadyForValidation,
+            UploadState::Draft,
+            UploadState::Unknown
+        ]
+    );
+    assert!(states[0].is_processing());
+    assert!(!states[1].is_processing());
+}
+
+#[test]
+fn list_cursors() {
+    use crate::{Cursor, List};
+
+    const SRC: &str = r#"{
+        "data": [1, 2],
+        "count": 2,
+        "total_count": 5,
+        "next_url": "https://api.lob.com/v1/postcards?limit=2&after=eyJkYXRlT2Zmc2V0IjoyfQ%3D%3D",
+        "previous_url": "/v1/postcards?limit=2&before=eyJkYXRlT2Zmc2V0IjowfQ%3D%3D"
+    }"#;
+
+    let list: List<u8> = serde_json::from_str(SRC).unwrap();
+
+    assert_eq!(list.total_count, Some(5));
+    assert_eq!(
+        list.next_cursor(),
+        Some(Cursor::After("eyJkYXRlT2Zmc2V0IjoyfQ==".into()))
+    );
+    assert_eq!(
+        list.previous_cursor(),
+        Some(Cursor::Before("eyJkYXRlT2Zmc2V0IjowfQ==".into()))
+    );
+
+    let last: List<u8> = serde_json::from_str(r#"{ "data": [], "count": 0 }"#).unwrap();
+    assert_eq!(last.next_cursor(), None);
+}
+
+#[tokio::test]
+async fn list_stream() {
+    use futures_util::TryStreamExt;
+
+    let page = |ids: &[&str], after: Option<&str>| {
+        let data: Vec<_> = ids
+            .iter()
+            .map(|id| serde_json::json!({ "id": id, "address_line1": "185 BERRY ST STE 6100" }))
+            .collect();
+        let next_url =
+            after.map(|after| format!("https://api.lob.com/v1/addresses?limit=2&after={after}"));
+
+        let page = serde_json::json!({ "data": data, "count": ids.len(), "next_url": next_url });
+        (200, page.to_string())
+    };
+
+    // the empty middle page still has a next cursor, which must be followed
+    let (base_url, requests) = serve(vec![
+        page(&["adr_1", "adr_2"], Some("cursor_1")),
+        page(&[], Some("cursor_2")),
+        page(&["adr_3"], None),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let addresses: Vec<_> = lob_client
+        .list_addresses()
+        .limit(2)
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<_> = addresses
+        .iter()
+        .map(|address| address.id.as_str())
+        .collect();
+    assert_eq!(ids, ["adr_1", "adr_2", "adr_3"]);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("GET /v1/addresses?limit=2 "));
+    assert!(requests[1].starts_with("GET /v1/addresses?limit=2&after=cursor_1 "));
+    assert!(requests[2].starts_with("GET /v1/addresses?limit=2&after=cursor_2 "));
+}
+
+#[test]
+fn jpeg_dimensions() {
+    use crate::informed_delivery::request::jpeg_dimensions;
+
+    // SOI, an APP0 segment, then a baseline frame header for a 300x200 image
+    const JPEG: &[u8] = &[
+        0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0xC8,
+        0x01, 0x2C, 0x03,
+    ];
+
+    assert_eq!(jpeg_dimensions(JPEG), Some((300, 200)));
+    assert_eq!(jpeg_dimensions(&JPEG[2..]), None);
+    assert_eq!(jpeg_dimensions(&JPEG[..10]), None);
+}
+
+#[test]
+fn rendered_file_url() {
+    use crate::{Postcard, RenderedFile, ThumbnailSize};
+
+    const SRC: &str = r#"{
+        "id": "psc_d2d10a2e9cba991c",
+        "to": { "id": "adr_1", "address_line1": "185 BERRY ST STE 6100" },
+        "url": "https://lob-assets.com/postcards/psc_d2d10a2e9cba991c.pdf?expires=1",
+        "thumbnails": [
+            { "small": "front_small.png", "medium": "front_medium.png", "large": "front_large.png" },
+            { "small": "back_small.png", "medium": "back_medium.png", "large": "back_large.png" }
+        ],
+        "size": "4x6",
+        "mail_type": "usps_first_class",
+        "send_date": "2024-05-01T18:00:00.000Z",
+        "date_created": "2024-05-01T17:00:00.000Z",
+        "date_modified": "2024-05-01T17:00:00.000Z"
+    }"#;
+
+    let postcard: Postcard = serde_json::from_str(SRC).unwrap();
+
+    assert_eq!(
+        RenderedFile::Pdf.url(&postcard),
+        Some("https://lob-assets.com/postcards/psc_d2d10a2e9cba991c.pdf?expires=1")
+    );
+    assert_eq!(
+        RenderedFile::Thumbnail(1, ThumbnailSize::Large).url(&postcard),
+        Some("back_large.png")
+    );
+    assert_eq!(
+        RenderedFile::Thumbnail(2, ThumbnailSize::Small).url(&postcard),
+        None
+    );
+}
+
+#[test]
+fn informed_delivery_target_url() {
+    use crate::informed_delivery::request::validate_target_url;
+
+    assert!(validate_target_url("https://example.com/case").is_ok());
+    assert!(validate_target_url("ftp://example.com").is_err());
+    assert!(validate_target_url("https://example.com\r\n--boundary").is_err());
+    assert!(validate_target_url(&format!("https://{}", "a".repeat(255))).is_err());
+}
+
+#[tokio::test]
+async fn informed_delivery_campaign() {
+    use crate::{
+        CampaignId, InformedDeliveryError, RIDE_ALONG_IMAGE_MAX_BYTES, RIDE_ALONG_IMAGE_WIDTH,
+    };
+
+    // SOI then a baseline frame header for a `width`x`height` image
+    fn jpeg(width: u16, height: u16) -> Vec<u8> {
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xC0, 0x00, 0x11, 0x08];
+        jpeg.extend(height.to_be_bytes());
+        jpeg.extend(width.to_be_bytes());
+        jpeg.push(0x03);
+        jpeg
+    }
+
+    let campaign_json = serde_json::json!({
+        "id": "idc_1d3b0a6e2f4c5b7a",
+        "campaign_id": "cmp_e05ee61ff80764b",
+        "target_url": "https://example.com/case",
+        "ride_along_image_url": "https://lob-assets.com/ride_along_image.jpg",
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z"
+    });
+
+    let (base_url, requests) = serve_with_bodies(vec![(200, campaign_json.to_string())]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let campaign = CampaignId::from("cmp_e05ee61ff80764b");
+    let target_url = "https://example.com/case";
+
+    let wrong_size = jpeg(RIDE_ALONG_IMAGE_WIDTH * 2, 200);
+    assert!(matches!(
+        lob_client
+            .create_informed_delivery_campaign(&campaign, target_url, &wrong_size)
+            .await,
+        Err(InformedDeliveryError::ImageDimensions {
+            width: 600,
+            height: 200
+        })
+    ));
+
+    let mut oversized = jpeg(RIDE_ALONG_IMAGE_WIDTH, 200);
+    oversized.resize(RIDE_ALONG_IMAGE_MAX_BYTES + 1, 0);
+    assert!(matches!(
+        lob_client
+            .create_informed_delivery_campaign(&campaign, target_url, &oversized)
+            .await,
+        Err(InformedDeliveryError::ImageSize(size)) if size == RIDE_ALONG_IMAGE_MAX_BYTES + 1
+    ));
+    assert!(requests.try_recv().is_err());
+
+    let image = jpeg(RIDE_ALONG_IMAGE_WIDTH, 200);
+    let informed_delivery_campaign = lob_client
+        .create_informed_delivery_campaign(&campaign, target_url, &image)
+        .await
+        .unwrap();
+    assert_eq!(informed_delivery_campaign.campaign_id, campaign);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(requests.len(), 1);
+    let (request_line, body) = &requests[0];
+    assert_eq!(
+        request_line,
+        "POST /v1/informed_delivery_campaigns HTTP/1.1"
+    );
+    assert!(body.contains(
+        "Content-Disposition: form-data; name=\"campaign_id\"\r\n\r\ncmp_e05ee61ff80764b\r\n"
+    ));
+    assert!(body.contains(
+        "Content-Disposition: form-data; name=\"target_url\"\r\n\r\nhttps://example.com/case\r\n"
+    ));
+    assert!(body.contains(
+        "Content-Disposition: form-data; name=\"ride_along_image\"; filename=\"ride_along_image.jpg\"\r\nContent-Type: image/jpeg\r\n\r\n"
+    ));
+}
+
+#[test]
+fn proof_request() {
+    use crate::{LobClient, ProofResource};
+
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build();
+
+    let postcard = lob_client
+        .create_postcard()
+        .idempotency_key("JP01-25-E01")
+        .name("Spanish Consulate")
+        .address_line_1("1800 Bering Dr")
+        .address_line_2("750")
+        .city("Houston")
+        .state("TX")
+        .zip_code("77057")
+        .front("<html>front</html>")
+        .back("<html>back</html>")
+        .use_type(UseType::Marketing)
+        .size(Size::FourBySix)
+        .mail_type(MailType::UspsFirstClass)
+        .description("Postcard To Spanish Consulate")
+        .build();
+
+    let proof: serde_json::Value =
+        serde_json::from_str(&postcard.proof_request().unwrap()).unwrap();
+
+    assert_eq!(proof["resource_type"], "postcard");
+    assert_eq!(proof["resource_parameters"]["size"], "4x6");
+    assert_eq!(proof["resource_parameters"]["front"], "<html>front</html>");
+    assert_eq!(
+        proof["resource_parameters"]["to"]["address_line1"],
+        "1800 Bering Dr"
+    );
+}
+
+#[test]
+fn webhook_signature() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{DEFAULT_WEBHOOK_TOLERANCE, WebhookSignatureError, verify_webhook_signature_at};
+
+    const SECRET: &[u8] = b"whsec_test";
+    const BODY: &[u8] = br#"{"id":"evt_123"}"#;
+    const TIMESTAMP: &str = "1700000000000";
+    const SIGNATURE: &str = "5559e2da63c13c48e4377de1f58292cea70f768295ddedf99851953a9f5f456f";
+
+    let now = UNIX_EPOCH + Duration::from_secs(1_700_000_010);
+    let verify = |body, signature, timestamp, now| {
+        verify_webhook_signature_at(
+            SECRET,
+            body,
+            signature,
+            timestamp,
+            DEFAULT_WEBHOOK_TOLERANCE,
+            now,
+        )
+    };
+
+    assert!(verify(BODY, SIGNATURE, TIMESTAMP, now).is_ok());
+    assert!(matches!(
+        verify(br#"{"id":"evt_124"}"#, SIGNATURE, TIMESTAMP, now),
+        Err(WebhookSignatureError::Mismatch)
+    ));
+    assert!(matches!(
+        verify(BODY, SIGNATURE, TIMESTAMP, now + Duration::from_secs(600)),
+        Err(WebhookSignatureError::Expired(_))
+    ));
+    assert!(matches!(
+        verify(BODY, "not hex", TIMESTAMP, now),
+        Err(WebhookSignatureError::MalformedSignature)
+    ));
+    assert!(matches!(
+        verify(BODY, SIGNATURE, "yesterday", now),
+        Err(WebhookSignatureError::MalformedTimestamp)
+    ));
+}
+
+#[test]
+fn lob_event() {
+    use crate::{LetterEvent, LobEvent, MailEvent};
+
+    const POSTCARD_MAILED: &str = r#"{
+        "id": "evt_d95ff8ffd2b5cfb4",
+        "reference_id": "psc_d2d10a2e9cba991c",
+        "date_created": "2024-05-01T18:10:01.000Z",
+        "event_type": { "id": "postcard.mailed", "resource": "postcards", "object": "event_type" },
+        "body": {
+            "id": "psc_d2d10a2e9cba991c",
+            "to": { "id": "adr_1", "address_line1": "185 BERRY ST STE 6100" },
+            "size": "4x6",
+            "mail_type": "usps_first_class",
+            "send_date": "2024-05-01T18:00:00.000Z",
+            "date_created": "2024-05-01T17:00:00.000Z",
+            "date_modified": "2024-05-01T18:10:00.000Z"
+        },
+        "object": "event"
+    }"#;
+
+    let event: LobEvent = serde_json::from_str(POSTCARD_MAILED).unwrap();
+    match &event {
+        LobEvent::Postcard(MailEvent::Mailed, event) => {
+            assert_eq!(event.body.id.as_str(), "psc_d2d10a2e9cba991c")
+        }
+        other => panic!("expected postcard.mailed, got {other:?}"),
+    }
+    assert_eq!(event.id().as_str(), "evt_d95ff8ffd2b5cfb4");
+
+    let certified = POSTCARD_MAILED
+        .replace("postcard.mailed", "letter.certified.delivered")
+        .replace(
+            r#""size": "4x6","#,
+            r#""from": { "id": "adr_2", "address_line1": "1 MAIN ST" },"#,
+        );
+    assert!(matches!(
+        serde_json::from_str(&certified).unwrap(),
+        LobEvent::Letter(LetterEvent::Certified(MailEvent::Delivered), _)
+    ));
+
+    let unknown = POSTCARD_MAILED.replace("postcard.mailed", "postcard.teleported");
+    match serde_json::from_str(&unknown).unwrap() {
+        LobEvent::Unknown(event_type, _) => assert_eq!(&*event_type, "postcard.teleported"),
+        other => panic!("expected an unknown event, got {other:?}"),
+    }
+
+    // a known event type whose body no longer matches the model is kept, not rejected
+    let new_size = POSTCARD_MAILED.replace(r#""size": "4x6""#, r#""size": "5x7.5""#);
+    match serde_json::from_str(&new_size).unwrap() {
+        LobEvent::Unknown(event_type, event) => {
+            assert_eq!(&*event_type, "postcard.mailed");
+            assert_eq!(event.id.as_str(), "evt_d95ff8ffd2b5cfb4");
+            assert_eq!(event.body["size"], "5x7.5");
+        }
+        other => panic!("expected an unknown event, got {other:?}"),
+    }
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn webhook_receiver() {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use axum::http::{HeaderMap, StatusCode};
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use crate::{LOB_SIGNATURE, LOB_SIGNATURE_TIMESTAMP, WebhookReceiver};
+
+    const SECRET: &[u8] = b"whsec_test";
+    const BODY: &[u8] = br#"{
+        "id": "evt_1",
+        "date_created": "2024-05-01T18:10:01.000Z",
+        "event_type": { "id": "check.mailed" },
+        "body": {}
+    }"#;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis()
+        .to_string();
+    let mut mac = Hmac::<Sha256>::new_from_slice(SECRET).unwrap();
+    mac.update(format!("{timestamp}.").as_bytes());
+    mac.update(BODY);
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    let mut headers = HeaderMap::new();
+    headers.insert(LOB_SIGNATURE_TIMESTAMP, timestamp.parse().unwrap());
+    headers.insert(LOB_SIGNATURE, signature.parse().unwrap());
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let receiver = WebhookReceiver::new(SECRET, |_| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        async { Ok::<(), ()>(()) }
+    });
+
+    assert_eq!(receiver.handle(&headers, BODY).await, StatusCode::OK);
+    assert_eq!(receiver.handle(&headers, BODY).await, StatusCode::OK);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    assert_eq!(
+        receiver.handle(&HeaderMap::new(), BODY).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    // the first attempt fails, so lob's retry must run the callback again
+    static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+    let receiver = WebhookReceiver::new(SECRET, |_| async {
+        match ATTEMPTS.fetch_add(1, Ordering::SeqCst) {
+            0 => Err(()),
+            _ => Ok(()),
+        }
+    });
+
+    assert_eq!(
+        receiver.handle(&headers, BODY).await,
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(receiver.handle(&headers, BODY).await, StatusCode::OK);
+    assert_eq!(receiver.handle(&headers, BODY).await, StatusCode::OK);
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 2);
+
+    // a redelivery while the first attempt is still running is not acknowledged
+    let receiver = WebhookReceiver::new(SECRET, |_| async {
+        tokio::task::yield_now().await;
+        Ok::<(), ()>(())
+    });
+
+    let (first, second) = tokio::join!(
+        receiver.handle(&headers, BODY),
+        receiver.handle(&headers, BODY)
+    );
+    assert_eq!((first, second), (StatusCode::OK, StatusCode::CONFLICT));
+    assert_eq!(receiver.handle(&headers, BODY).await, StatusCode::OK);
+}
+
+/// A retrieved postcard with the given `(id, name)` tracking events
+fn postcard_json(tracking_events: &[(&str, &str)], deleted: bool) -> String {
+    let tracking_events: Vec<_> = tracking_events
+        .iter()
+        .map(|(id, name)| {
+            serde_json::json!({
+                "id": id,
+                "name": name,
+                "type": "normal",
+                "date_created": "2024-05-02T00:00:00.000Z"
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "id": "psc_d2d10a2e9cba991c",
+        "to": { "id": "adr_1", "address_line1": "185 BERRY ST STE 6100" },
+        "size": "4x6",
+        "mail_type": "usps_first_class",
+        "send_date": "2024-05-01T18:00:00.000Z",
+        "date_created": "2024-05-01T17:00:00.000Z",
+        "date_modified": "2024-05-01T17:00:00.000Z",
+        "tracking_events": tracking_events,
+        "deleted": deleted
+    })
+    .to_string()
+}
+
+#[test]
+fn tracking_event_order() {
+    use crate::TrackingEventName::*;
+
+    assert!(Delivered.reaches(Mailed));
+    assert!(InTransit.reaches(Mailed));
+    assert!(PickupAvailable.reaches(ProcessedForDelivery));
+    assert!(!Mailed.reaches(Delivered));
+    assert!(!InTransit.reaches(ReRouted));
+    assert!(!ReturnedToSender.reaches(Delivered));
+    assert!(ReturnedToSender.reaches(ReturnedToSender));
+}
+
+#[tokio::test(start_paused = true)]
+async fn watch_postcard_status() {
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use tokio::time::Instant;
+
+    use crate::{PostcardError, PostcardId, PostcardStatusUpdate, TrackingEventName};
+
+    async fn watch(
+        responses: Vec<String>,
+        target: TrackingEventName,
+        timeout: Duration,
+    ) -> Vec<Result<PostcardStatusUpdate, PostcardError>> {
+        let (base_url, _) = serve(responses.into_iter().map(|body| (200, body)).collect());
+        let lob_client = LobClient::builder()
+            .client(reqwest::Client::new())
+            .api_key("test_key")
+            .build_with_base_url(&base_url);
+
+        lob_client
+            .watch_postcard_status(&PostcardId::from("psc_d2d10a2e9cba991c"), target, timeout)
+            .collect()
+            .await
+    }
+
+    fn names(updates: &[Result<PostcardStatusUpdate, PostcardError>]) -> Vec<String> {
+        updates
+            .iter()
+            .map(|update| match update {
+                Ok(PostcardStatusUpdate::Tracking(event)) => format!("{:?}", event.name),
+                Ok(PostcardStatusUpdate::Reached(_)) => "Reached".into(),
+                Err(err) => format!("Err({err})"),
+            })
+            .collect()
+    }
+
+    // new events are yielded once, and delivery reaches an in local area target usps never scanned
+    let start = Instant::now();
+    let updates = watch(
+        vec![
+            postcard_json(&[("evnt_1", "Mailed")], false),
+            postcard_json(&[("evnt_1", "Mailed"), ("evnt_2", "In Transit")], false),
+            postcard_json(
+                &[
+                    ("evnt_1", "Mailed"),
+                    ("evnt_2", "In Transit"),
+                    ("evnt_3", "Delivered"),
+                ],
+                false,
+            ),
+        ],
+        TrackingEventName::InLocalArea,
+        Duration::from_secs(3600),
+    )
+    .await;
+    assert_eq!(
+        names(&updates),
+        ["Mailed", "InTransit", "Delivered", "Reached"]
+    );
+    // waits 30s then backs off to 60s
+    assert_eq!(start.elapsed().as_secs(), 90);
+
+    // checks at 0s and 30s, then once more at the deadline
+    let updates = watch(
+        vec![postcard_json(&[("evnt_1", "Mailed")], false); 3],
+        TrackingEventName::Delivered,
+        Duration::from_secs(45),
+    )
+    .await;
+    assert!(matches!(
+        updates[..],
+        [
+            Ok(_),
+            Err(PostcardError::Timeout(TrackingEventName::Delivered))
+        ]
+    ));
+
+    let updates = watch(
+        vec![postcard_json(&[], true)],
+        TrackingEventName::Delivered,
+        Duration::from_secs(3600),
+    )
+    .await;
+    assert!(matches!(updates[..], [Err(PostcardError::Cancelled)]));
+
+    let updates = watch(
+        vec![postcard_json(
+            &[("evnt_1", "Mailed"), ("evnt_2", "Returned to Sender")],
+            false,
+        )],
+        TrackingEventName::Delivered,
+        Duration::from_secs(3600),
+    )
+    .await;
+    assert!(matches!(
+        updates[..],
+        [Ok(_), Ok(_), Err(PostcardError::ReturnedToSender)]
+    ));
+}
+
+#[test]
+fn print_order_quantity() {
+    use crate::{
+        BUCKSLIP_ORDER_MIN_QUANTITY, BuckslipOrderQuantity, CARD_ORDER_MIN_QUANTITY,
+        CardOrderQuantity,
+    };
+
+    assert_eq!(
+        BuckslipOrderQuantity::new(BUCKSLIP_ORDER_MIN_QUANTITY - 1),
+        None
+    );
+    assert_eq!(
+        BuckslipOrderQuantity::new(BUCKSLIP_ORDER_MIN_QUANTITY).map(BuckslipOrderQuantity::get),
+        Some(BUCKSLIP_ORDER_MIN_QUANTITY)
+    );
+    assert_eq!(CardOrderQuantity::new(BUCKSLIP_ORDER_MIN_QUANTITY), None);
+    assert_eq!(
+        CardOrderQuantity::new(CARD_ORDER_MIN_QUANTITY).map(CardOrderQuantity::get),
+        Some(CARD_ORDER_MIN_QUANTITY)
+    );
+}
+
+#[tokio::test]
+async fn print_orders() {
+    use crate::{
+        BuckslipId, BuckslipOrderQuantity, BuckslipSize, CARD_ORDER_MIN_QUANTITY, CardId,
+        CardOrderQuantity, CardSize, PrintOrderStatus,
+    };
+
+    let buckslip = serde_json::json!({
+        "id": "bck_7a6d73c5c8457fc",
+        "description": "Spring promo",
+        "size": "8.75x3.75",
+        "front_original_url": "https://example.com/front.pdf",
+        "available_quantity": 4000,
+        "allocated_quantity": 1000,
+        "onhand_quantity": 5000,
+        "auto_reorder": true,
+        "reorder_quantity": 5000,
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-02T00:00:00.000Z"
+    });
+    let buckslip_order = serde_json::json!({
+        "id": "bo_2c6d1d45a8de5b2",
+        "buckslip_id": "bck_7a6d73c5c8457fc",
+        "status": "pending",
+        "quantity_ordered": 5000,
+        "unit_price": "0.02",
+        "date_created": "2024-01-03T00:00:00.000Z",
+        "date_modified": "2024-01-03T00:00:00.000Z"
+    });
+    let card_orders = serde_json::json!({
+        "data": [{
+            "id": "co_0c4b1d9e7a4d3f2",
+            "card_id": "card_a4c6e8b0d2f4a6c",
+            "status": "available",
+            "quantity_ordered": 10000,
+            "inventory": 9500,
+            "availability_date": "2024-02-01T00:00:00.000Z",
+            "date_created": "2024-01-03T00:00:00.000Z",
+            "date_modified": "2024-02-01T00:00:00.000Z"
+        }],
+        "count": 1
+    });
+    let card = serde_json::json!({
+        "id": "card_a4c6e8b0d2f4a6c",
+        "size": "2.125x3.375",
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z"
+    });
+
+    let (base_url, requests) = serve(vec![
+        (200, buckslip.to_string()),
+        (200, buckslip_order.to_string()),
+        (200, card_orders.to_string()),
+        (200, card.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let buckslip_id = BuckslipId::from("bck_7a6d73c5c8457fc");
+    let buckslip = lob_client
+        .update_buckslip(&buckslip_id)
+        .auto_reorder(BuckslipOrderQuantity::new(5_000).unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(buckslip.id, buckslip_id);
+    assert_eq!(
+        buckslip.size,
+        BuckslipSize::EightAndThreeQuartersByThreeAndThreeQuarters
+    );
+    assert_eq!(buckslip.pending_quantity, 0);
+    assert_eq!(buckslip.reorder_quantity, Some(5_000));
+
+    let order = lob_client
+        .create_buckslip_order(&buckslip_id, BuckslipOrderQuantity::new(5_000).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(order.buckslip_id, buckslip_id);
+    assert_eq!(order.status, PrintOrderStatus::Pending);
+    assert_eq!(order.inventory, 0);
+
+    let card_id = CardId::from("card_a4c6e8b0d2f4a6c");
+    let orders = lob_client.list_card_orders(&card_id).send().await.unwrap();
+    assert_eq!(orders.data[0].card_id, card_id);
+    assert_eq!(orders.data[0].status, PrintOrderStatus::Available);
+
+    let card = lob_client
+        .create_card()
+        .front("https://example.com/card.pdf")
+        .size(CardSize::TwoAndOneEighthByThreeAndThreeEighths)
+        .auto_reorder(CardOrderQuantity::new(CARD_ORDER_MIN_QUANTITY).unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(card.id, card_id);
+    assert!(!card.auto_reorder);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "PATCH /v1/buckslips/bck_7a6d73c5c8457fc HTTP/1.1",
+            "POST /v1/buckslips/bck_7a6d73c5c8457fc/orders HTTP/1.1",
+            "GET /v1/cards/card_a4c6e8b0d2f4a6c/orders HTTP/1.1",
+            "POST /v1/cards HTTP/1.1",
+        ]
+    );
+}
+
+#[test]
+fn tracking_event() {
+    use crate::{TrackingEvent, TrackingEventName};
+
+    const SRC: &str = r#"[
+        {
+            "id": "evnt_9e84094c9368cfb",
+            "name": "In Local Area",
+            "type": "normal",
+            "location": "72231",
+            "time": "2024-01-05T17:20:35.000Z",
+            "date_created": "2024-01-05T18:00:00.000Z",
+            "object": "tracking_event"
+        },
+        {
+            "id": "evnt_0b6e4fc2b9b3a1d",
+            "name": "Held at Facility",
+            "type": "certified",
+            "location": null,
+            "date_created": "2024-01-06T18:00:00.000Z",
+            "object": "tracking_event"
+        }
+    ]"#;
+
+    let events: Vec<TrackingEvent> = serde_json::from_str(SRC).unwrap();
+
+    assert_eq!(events[0].id.as_str(), "evnt_9e84094c9368cfb");
+    assert_eq!(events[0].name, TrackingEventName::InLocalArea);
+    assert_eq!(&*events[0].event_type, "normal");
+    assert_eq!(events[0].location.as_deref(), Some("72231"));
+    assert_eq!(events[1].name, TrackingEventName::Unknown);
+    assert_eq!(events[1].location, None);
+    assert_eq!(events[1].time, None);
+}
+
+#[tokio::test]
+async fn list_events_date_range() {
+    let (base_url, requests) = serve(vec![(200, r#"{ "data": [], "count": 0 }"#.to_owned())]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let events = lob_client
+        .list_events()
+        .date_created_after("2024-01-01T00:00:00Z")
+        .date_created_before("2024-01-02T00:00:00Z")
+        .send()
+        .await
+        .unwrap();
+
+    assert!(events.data.is_empty());
+    assert_eq!(
+        requests.recv().unwrap(),
+        "GET /v1/events?date_created%5Bgt%5D=2024-01-01T00%3A00%3A00Z&date_created%5Blt%5D=2024-01-02T00%3A00%3A00Z HTTP/1.1"
+    );
+}
+
+#[tokio::test]
+async fn url_shortener() {
+    use crate::{LinkRequest, Metadata};
+
+    let domain = serde_json::json!({
+        "id": "dom_5e4ba5cb6c4ab1a",
+        "domain": "go.example.com",
+        "status": "pending",
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z"
+    });
+    let link = |id: &str, slug: &str| {
+        serde_json::json!({
+            "id": id,
+            "domain": "go.example.com",
+            "short_link": format!("https://go.example.com/{slug}"),
+            "redirect_link": "https://example.com/offer",
+            "slug": slug,
+            "click_count": 3,
+            "metadata": { "campaign": "spring" },
+            "date_created": "2024-01-01T00:00:00.000Z",
+            "date_modified": "2024-01-01T00:00:00.000Z"
+        })
+    };
+    let bulk = serde_json::json!({
+        "links": [link("lnk_2", "b"), link("lnk_3", "c")]
+    });
+
+    let (base_url, requests) = serve(vec![
+        (200, domain.to_string()),
+        (200, link("lnk_1", "a").to_string()),
+        (200, bulk.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let domain = lob_client.create_domain("go.example.com").await.unwrap();
+    assert_eq!(domain.id.as_str(), "dom_5e4ba5cb6c4ab1a");
+    assert_eq!(domain.status.as_deref(), Some("pending"));
+
+    let request = LinkRequest {
+        redirect_link: "https://example.com/offer",
+        domain: "go.example.com",
+        slug: Some("a"),
+        metadata: Some(Metadata(&[("campaign", "spring")])),
+    };
+    assert_eq!(
+        serde_json::to_value(request).unwrap(),
+        serde_json::json!({
+            "redirect_link": "https://example.com/offer",
+            "domain": "go.example.com",
+            "slug": "a",
+            "metadata": { "campaign": "spring" }
+        })
+    );
+
+    let link = lob_client.create_link(&request).await.unwrap();
+    assert_eq!(link.id.as_str(), "lnk_1");
+    assert_eq!(&*link.short_link, "https://go.example.com/a");
+    assert_eq!(link.click_count, 3);
+    assert_eq!(
+        link.metadata.get("campaign").map(AsRef::as_ref),
+        Some("spring")
+    );
+
+    let links = lob_client
+        .create_links(&[
+            LinkRequest {
+                slug: Some("b"),
+                metadata: None,
+                ..request
+            },
+            LinkRequest {
+                slug: None,
+                metadata: None,
+                ..request
+            },
+        ])
+        .await
+        .unwrap();
+    let ids: Vec<_> = links.iter().map(|link| link.id.as_str()).collect();
+    assert_eq!(ids, ["lnk_2", "lnk_3"]);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/domains HTTP/1.1",
+            "POST /v1/links HTTP/1.1",
+            "POST /v1/links/bulk HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn campaign() {
+    use crate::{
+        BillingGroupId, Metadata, ScheduleType, UseType, campaign::request::CreateCampaignJson,
+    };
+
+    let billing_group_id = BillingGroupId::from("bg_4bb02b527a9b9ac");
+    let request = CreateCampaignJson {
+        name: "Spring",
+        use_type: UseType::Marketing,
+        schedule_type: ScheduleType::Immediate,
+        target_delivery_date: None,
+        description: None,
+        send_date: None,
+        cancel_window_campaign_minutes: Some(60),
+        metadata: Some(Metadata(&[("team", "growth")])),
+        billing_group_id: Some(&billing_group_id),
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "name": "Spring",
+            "use_type": "marketing",
+            "schedule_type": "immediate",
+            "cancel_window_campaign_minutes": 60,
+            "metadata": { "team": "growth" },
+            "billing_group_id": "bg_4bb02b527a9b9ac"
+        })
+    );
+
+    let campaign = |is_draft: bool, status: &str| {
+        serde_json::json!({
+            "id": "cmp_e05ee61ff80764b",
+            "name": "Spring",
+            "schedule_type": "immediate",
+            "cancel_window_campaign_minutes": 60,
+            "use_type": "marketing",
+            "is_draft": is_draft,
+            "status": status,
+            "billing_group_id": "bg_4bb02b527a9b9ac",
+            "metadata": { "team": "growth" },
+            "date_created": "2024-01-01T00:00:00.000Z",
+            "date_modified": "2024-01-01T00:00:00.000Z"
+        })
+    };
+
+    let (base_url, requests) = serve(vec![
+        (200, campaign(true, "draft").to_string()),
+        (200, campaign(false, "sent").to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let campaign = lob_client
+        .create_campaign()
+        .name("Spring")
+        .use_type(UseType::Marketing)
+        .immediate()
+        .billing_group_id(&billing_group_id)
+        .send()
+        .await
+        .unwrap();
+    assert!(campaign.is_draft);
+    assert_eq!(campaign.schedule_type, ScheduleType::Immediate);
+    assert_eq!(campaign.billing_group_id, Some(billing_group_id));
+    assert_eq!(campaign.target_delivery_date, None);
+
+    let campaign = lob_client.send_campaign(&campaign.id).await.unwrap();
+    assert!(!campaign.is_draft);
+    assert_eq!(campaign.status.as_deref(), Some("sent"));
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/campaigns HTTP/1.1",
+            "POST /v1/campaigns/cmp_e05ee61ff80764b/send HTTP/1.1",
+        ]
+    );
+}
+
+/// the `to` of a postcard finished from `builder`, as sent to lob
+fn postcard_recipient(
+    builder: crate::create_postcard::builder::CreatePostcardBuilderWithZipCode<
+        '_,
+        '_,
+        '_,
+        '_,
+        '_,
+        '_,
+        '_,
+        '_,
+        '_,
+    >,
+) -> serde_json::Value {
+    let postcard = builder
+        .front("<html>front</html>")
+        .back("<html>back</html>")
+        .use_type(UseType::Operational)
+        .size(Size::FourBySix)
+        .mail_type(MailType::UspsFirstClass)
+        .description("Recipient")
+        .build();
+
+    serde_json::to_value(&postcard.json_request).unwrap()["to"].take()
+}
+
+#[tokio::test]
+async fn address_book() {
+    use crate::{AddressId, CountryCode, Metadata};
+
+    let address = serde_json::json!({
+        "id": "adr_d3489cd64c791ab5",
+        "description": "Harry - Office",
+        "name": "HARRY ZHANG",
+        "company": "LOB",
+        "phone": "5555555555",
+        "email": "harry@lob.com",
+        "address_line1": "210 KING ST",
+        "address_line2": null,
+        "address_city": "SAN FRANCISCO",
+        "address_state": "CA",
+        "address_zip": "94107-1741",
+        "address_country": "UNITED STATES",
+        "metadata": { "team": "growth" },
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z",
+        "object": "address"
+    });
+    let deleted = serde_json::json!({ "id": "adr_d3489cd64c791ab5", "deleted": true });
+
+    let (base_url, requests) = serve(vec![(200, address.to_string()), (200, deleted.to_string())]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let request = lob_client
+        .create_address()
+        .name("Harry Zhang")
+        .address_line_1("210 King St")
+        .city("San Francisco")
+        .state("CA")
+        .zip_code("94107")
+        .company("Lob")
+        .description("Harry - Office")
+        .country(CountryCode::new("US").unwrap())
+        .metadata(Metadata(&[("team", "growth")]))
+        .build();
+    assert_eq!(
+        serde_json::to_value(&request.json_request).unwrap(),
+        serde_json::json!({
+            "name": "Harry Zhang",
+            "address_line1": "210 King St",
+            "address_city": "San Francisco",
+            "address_state": "CA",
+            "address_zip": "94107",
+            "company": "Lob",
+            "description": "Harry - Office",
+            "address_country": "US",
+            "metadata": { "team": "growth" }
+        })
+    );
+
+    let address = request.send().await.unwrap();
+    assert_eq!(address.id.as_str(), "adr_d3489cd64c791ab5");
+    assert_eq!(address.name.as_deref(), Some("HARRY ZHANG"));
+    assert_eq!(address.address_line2, None);
+    assert_eq!(address.address_zip.as_deref(), Some("94107-1741"));
+    assert_eq!(
+        address.metadata.get("team").map(AsRef::as_ref),
+        Some("growth")
+    );
+
+    let deleted = lob_client.delete_address(&address.id).await.unwrap();
+    assert_eq!(deleted.id, address.id);
+    assert!(deleted.deleted);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/addresses HTTP/1.1",
+            "DELETE /v1/addresses/adr_d3489cd64c791ab5 HTTP/1.1",
+        ]
+    );
+
+    let saved = AddressId::from("adr_d3489cd64c791ab5");
+    let builder = lob_client.create_postcard().idempotency_key("key");
+    assert_eq!(
+        postcard_recipient(builder.to(&saved)),
+        serde_json::json!("adr_d3489cd64c791ab5")
+    );
+
+    let builder = lob_client
+        .create_postcard()
+        .idempotency_key("key")
+        .name("Harry Zhang")
+        .address_line_1("210 King St")
+        .city("San Francisco")
+        .state("CA")
+        .zip_code("94107");
+    assert_eq!(
+        postcard_recipient(builder),
+        serde_json::json!({
+            "name": "Harry Zhang",
+            "address_line1": "210 King St",
+            "address_line2": null,
+            "address_city": "San Francisco",
+            "address_state": "CA",
+            "address_zip": "94107"
+        })
+    );
+}
+
+#[test]
+fn verified_address() {
+    use crate::UsVerification;
+
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build();
+
+    let verification = |secondary_line: &str| -> UsVerification {
+        serde_json::from_value(serde_json::json!({
+            "primary_line": "185 BERRY ST",
+            "secondary_line": secondary_line,
+            "last_line": "SAN FRANCISCO CA 94107-1728",
+            "deliverability": "deliverable",
+            "components": { "city": "SAN FRANCISCO", "state": "CA", "zip_code": "94107" },
+            "deliverability_analysis": { "dpv_confirmation": "Y", "dpv_cmra": "N", "dpv_vacant": "N" }
+        }))
+        .unwrap()
+    };
+
+    let without_secondary = verification("");
+    let builder = lob_client
+        .create_postcard()
+        .idempotency_key("key")
+        .name("Lob")
+        .verified_address(&without_secondary);
+    assert_eq!(
+        postcard_recipient(builder),
+        serde_json::json!({
+            "name": "Lob",
+            "address_line1": "185 BERRY ST",
+            "address_line2": null,
+            "address_city": "SAN FRANCISCO",
+            "address_state": "CA",
+            "address_zip": "94107"
+        })
+    );
+
+    let with_secondary = verification("STE 6100");
+    let builder = lob_client
+        .create_postcard()
+        .idempotency_key("key")
+        .name("Lob")
+        .verified_address(&with_secondary);
+    assert_eq!(postcard_recipient(builder)["address_line2"], "STE 6100");
+}
+
+#[tokio::test]
+async fn us_autocompletion() {
+    let autocompletion = serde_json::json!({
+        "id": "us_auto_a1b2c3d4e5f6a7b8c9d0",
+        "suggestions": [
+            { "primary_line": "185 BERRY ST", "city": "SAN FRANCISCO", "state": "CA", "zip_code": "94107", "object": "us_autocompletion_suggestion" },
+            { "primary_line": "185 BERRY ST", "city": "SAN DIEGO", "state": "CA", "zip_code": "92101", "object": "us_autocompletion_suggestion" }
+        ],
+        "object": "us_autocompletion"
+    });
+
+    let (base_url, requests) = serve(vec![(200, autocompletion.to_string())]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let autocompletion = lob_client
+        .autocomplete_us_address()
+        .address_prefix("185 Berry")
+        .state("CA")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        autocompletion.id.as_deref(),
+        Some("us_auto_a1b2c3d4e5f6a7b8c9d0")
+    );
+    assert_eq!(autocompletion.suggestions.len(), 2);
+    assert_eq!(&*autocompletion.suggestions[1].zip_code, "92101");
+    assert_eq!(
+        requests.recv().unwrap(),
+        "POST /v1/us_autocompletions HTTP/1.1"
+    );
+
+    let builder = lob_client
+        .create_postcard()
+        .idempotency_key("key")
+        .name("Lob")
+        .suggested_address(&autocompletion.suggestions[0]);
+    assert_eq!(
+        postcard_recipient(builder),
+        serde_json::json!({
+            "name": "Lob",
+            "address_line1": "185 BERRY ST",
+            "address_line2": null,
+            "address_city": "SAN FRANCISCO",
+            "address_state": "CA",
+            "address_zip": "94107"
+        })
+    );
+}
+
+#[tokio::test]
+async fn us_lookups() {
+    use crate::ZipCodeType;
+
+    let zip_lookup = serde_json::json!({
+        "id": "us_zip_c7cb63d68f8d6",
+        "zip_code": "94107",
+        "zip_code_type": "standard",
+        "cities": [
+            { "city": "SAN FRANCISCO", "state": "CA", "county": "SAN FRANCISCO", "county_fips": "06075", "preferred": true }
+        ],
+        "object": "us_zip_lookup"
+    });
+    let invalid_zip_lookup = serde_json::json!({
+        "zip_code": "00000",
+        "zip_code_type": "",
+        "cities": [],
+        "object": "us_zip_lookup"
+    });
+    let reverse_geocode = serde_json::json!({
+        "id": "us_reverse_geocode_3b5f9e",
+        "addresses": [
+            {
+                "components": { "zip_code": "94107", "zip_code_plus_4": "1702" },
+                "location_analysis": { "latitude": 37.777456, "longitude": -122.393039, "distance": 0.04 }
+            },
+            {
+                "components": { "zip_code": "94158" },
+                "location_analysis": { "latitude": 37.770, "longitude": -122.389, "distance": 0.62 }
+            }
+        ],
+        "object": "us_reverse_geocode_lookup"
+    });
+
+    let (base_url, requests) = serve(vec![
+        (200, zip_lookup.to_string()),
+        (200, invalid_zip_lookup.to_string()),
+        (200, reverse_geocode.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let zip_lookup = lob_client.lookup_zip_code("94107").await.unwrap();
+    assert_eq!(zip_lookup.zip_code_type, ZipCodeType::Standard);
+    assert_eq!(&*zip_lookup.cities[0].county_fips, "06075");
+    assert!(zip_lookup.cities[0].preferred);
+
+    let invalid_zip_lookup = lob_client.lookup_zip_code("00000").await.unwrap();
+    assert_eq!(invalid_zip_lookup.zip_code_type, ZipCodeType::Invalid);
+    assert!(invalid_zip_lookup.cities.is_empty());
+
+    let reverse_geocode = lob_client
+        .reverse_geocode(37.777456, -122.393039)
+        .size(2)
+        .send()
+        .await
+        .unwrap();
+    let addresses = &reverse_geocode.addresses;
+    assert_eq!(&*addresses[0].components.zip_code_plus_4, "1702");
+    assert_eq!(&*addresses[1].components.zip_code_plus_4, "");
+    assert_eq!(addresses[1].location_analysis.distance, 0.62);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/us_zip_lookups HTTP/1.1",
+            "POST /v1/us_zip_lookups HTTP/1.1",
+            "POST /v1/us_reverse_geocode_lookups?size=2 HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn identity_validation() {
+    use crate::IdentityConfidence;
+
+    let validation = |score: Option<f64>, confidence: &str| {
+        serde_json::json!({
+            "id": "id_validation_c9e6e36f6b3f4e1a",
+            "recipient": "HARRY ZHANG",
+            "primary_line": "210 KING ST",
+            "secondary_line": "",
+            "last_line": "SAN FRANCISCO CA 94107",
+            "score": score,
+            "confidence": confidence,
+            "object": "id_validation"
+        })
+    };
+
+    let (base_url, requests) = serve(vec![
+        (200, validation(Some(100.0), "high").to_string()),
+        (200, validation(Some(55.5), "medium").to_string()),
+        (200, validation(Some(10.0), "low").to_string()),
+        (200, validation(None, "").to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let mut results = Vec::new();
+    for _ in 0..4 {
+        let validation = lob_client
+            .validate_identity()
+            .name("Harry Zhang")
+            .address_line_1("210 King St")
+            .city("San Francisco")
+            .state("CA")
+            .zip_code("94107")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(&*validation.recipient, "HARRY ZHANG");
+        results.push((validation.score, validation.confidence));
+    }
+
+    assert_eq!(
+        results,
+        [
+            (Some(100.0), IdentityConfidence::High),
+            (Some(55.5), IdentityConfidence::Medium),
+            (Some(10.0), IdentityConfidence::Low),
+            (None, IdentityConfidence::None),
+        ]
+    );
+    assert_eq!(
+        requests.recv().unwrap(),
+        "POST /v1/identity_validation HTTP/1.1"
+    );
+}
+
+#[tokio::test]
+async fn billing_groups() {
+    let billing_group = |description: Option<&str>| {
+        serde_json::json!({
+            "id": "bg_4bb02b527a9b9ac",
+            "name": "Marketing",
+            "description": description,
+            "date_created": "2024-01-01T00:00:00.000Z",
+            "date_modified": "2024-01-02T00:00:00.000Z",
+            "object": "billing_group"
+        })
+    };
+    let billing_groups = serde_json::json!({
+        "data": [billing_group(Some("Spring campaigns")), billing_group(None)],
+        "count": 2,
+        "total_count": 2
+    });
+
+    let (base_url, requests) = serve(vec![
+        (200, billing_group(None).to_string()),
+        (200, billing_group(Some("Spring campaigns")).to_string()),
+        (200, billing_groups.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let billing_group = lob_client
+        .create_billing_group()
+        .name("Marketing")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(billing_group.id.as_str(), "bg_4bb02b527a9b9ac");
+    assert_eq!(billing_group.description, None);
+
+    let billing_group = lob_client
+        .update_billing_group(&billing_group.id)
+        .description("Spring campaigns")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        billing_group.description.as_deref(),
+        Some("Spring campaigns")
+    );
+
+    let billing_groups = lob_client
+        .list_billing_groups()
+        .include_total_count()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(billing_groups.data.len(), 2);
+    assert_eq!(billing_groups.total_count, Some(2));
+    assert_eq!(&*billing_groups.data[1].name, "Marketing");
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/billing_groups HTTP/1.1",
+            "POST /v1/billing_groups/bg_4bb02b527a9b9ac HTTP/1.1",
+            "GET /v1/billing_groups?include%5B%5D=total_count HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn qr_code_scans() {
+    use crate::PostcardId;
+
+    let scans = serde_json::json!({
+        "data": [
+            {
+                "resource_id": "psc_5c002b86ce47537a",
+                "number_of_scans": 2,
+                "scans": [
+                    { "ip_location": "San Francisco, CA", "scan_date": "2024-01-10T17:05:02.000Z" },
+                    { "ip_location": null, "scan_date": "2024-01-11T09:30:00.000Z" }
+                ]
+            },
+            { "resource_id": "psc_a3c9e1f0b2d4c6e8", "number_of_scans": 0 }
+        ],
+        "count": 2,
+        "object": "list"
+    });
+
+    let (base_url, requests) = serve(vec![(200, scans.to_string())]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let resource_ids = [
+        PostcardId::from("psc_5c002b86ce47537a"),
+        PostcardId::from("psc_a3c9e1f0b2d4c6e8"),
+    ];
+    let scans = lob_client
+        .list_qr_code_scans()
+        .scanned(true)
+        .resource_ids(&resource_ids)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(scans.data[0].resource_id, resource_ids[0]);
+    assert_eq!(scans.data[0].number_of_scans, 2);
+    assert_eq!(
+        scans.data[0].scans[0].ip_location.as_deref(),
+        Some("San Francisco, CA")
+    );
+    assert_eq!(scans.data[0].scans[1].ip_location, None);
+    assert!(scans.data[1].scans.is_empty());
+    assert_eq!(
+        requests.recv().unwrap(),
+        "GET /v1/qr_code_analytics?scanned=true&resource_ids%5B%5D=psc_5c002b86ce47537a&resource_ids%5B%5D=psc_a3c9e1f0b2d4c6e8 HTTP/1.1"
+    );
+}
+
+/// a mail piece as lob returns it, with the fields shared by self mailers, snap packs and booklets
+fn mail_piece_json(id: &str, size: &str, deleted: bool) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "description": "Spring offer",
+        "to": {
+            "id": "adr_d3489cd64c791ab5",
+            "name": "HARRY ZHANG",
+            "address_line1": "210 KING ST",
+            "address_city": "SAN FRANCISCO",
+            "address_state": "CA",
+            "address_zip": "94107-1741",
+            "address_country": "UNITED STATES"
+        },
+        "from": {
+            "id": "adr_210a8d4b0b76d77b",
+            "name": "LEORE AVIDAR",
+            "address_line1": "185 BERRY ST STE 6100",
+            "address_city": "SAN FRANCISCO",
+            "address_state": "CA",
+            "address_zip": "94107-1728"
+        },
+        "url": "https://lob-assets.com/rendered.pdf?expires=1",
+        "size": size,
+        "mail_type": "usps_first_class",
+        "use_type": "marketing",
+        "metadata": { "team": "growth" },
+        "send_date": "2024-01-05T00:00:00.000Z",
+        "expected_delivery_date": "2024-01-10",
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z",
+        "tracking_events": [{
+            "id": "evnt_9e84094c9368cfb",
+            "name": "Mailed",
+            "type": "normal",
+            "location": "94107",
+            "time": "2024-01-05T17:20:35.000Z",
+            "date_created": "2024-01-05T18:00:00.000Z"
+        }],
+        "deleted": deleted
+    })
+}
+
+#[tokio::test]
+async fn self_mailers() {
+    use crate::{AddressId, Metadata, SelfMailerId, SelfMailerSize, TrackingEventName};
+
+    let self_mailer = mail_piece_json("sfm_8ffbe811dea49dcf", "6x18_bifold", false);
+    let self_mailers = serde_json::json!({ "data": [self_mailer], "count": 1 });
+    let cancelled = serde_json::json!({ "id": "sfm_8ffbe811dea49dcf", "deleted": true });
+
+    let (base_url, requests) = serve(vec![
+        (200, self_mailer.to_string()),
+        (200, self_mailer.to_string()),
+        (200, self_mailers.to_string()),
+        (200, cancelled.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let to = AddressId::from("adr_d3489cd64c791ab5");
+    let from = AddressId::from("adr_210a8d4b0b76d77b");
+    let request = lob_client
+        .create_self_mailer()
+        .idempotency_key("SFM-01")
+        .to(&to)
+        .inside("https://example.com/inside.pdf")
+        .outside("https://example.com/outside.pdf")
+        .use_type(UseType::Marketing)
+        .size(SelfMailerSize::SixByEighteenBifold)
+        .mail_type(MailType::UspsFirstClass)
+        .description("Spring offer")
+        .from(&from)
+        .metadata(Metadata(&[("team", "growth")]))
+        .merge(serde_json::json!({ "name": "Harry" }));
+    assert_eq!(
+        serde_json::to_value(&request.json_request).unwrap(),
+        serde_json::json!({
+            "to": "adr_d3489cd64c791ab5",
+            "inside": "https://example.com/inside.pdf",
+            "outside": "https://example.com/outside.pdf",
+            "size": "6x18_bifold",
+            "mail_type": "usps_first_class",
+            "merge_variables": { "name": "Harry" },
+            "description": "Spring offer",
+            "from": "adr_210a8d4b0b76d77b",
+            "use_type": "marketing",
+            "metadata": { "team": "growth" }
+        })
+    );
+
+    let self_mailer = request.send().await.unwrap();
+    let id = SelfMailerId::from("sfm_8ffbe811dea49dcf");
+    assert_eq!(self_mailer.id, id);
+    assert_eq!(self_mailer.size, SelfMailerSize::SixByEighteenBifold);
+    assert_eq!(self_mailer.to.id, to);
+    assert_eq!(self_mailer.from.map(|from| from.id), Some(from));
+    assert_eq!(self_mailer.use_type, Some(UseType::Marketing));
+    assert!(!self_mailer.deleted);
+
+    let tracking_events = lob_client.self_mailer_tracking_events(&id).await.unwrap();
+    assert_eq!(tracking_events[0].name, TrackingEventName::Mailed);
+
+    let self_mailers = lob_client
+        .list_self_mailers()
+        .limit(1)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(self_mailers.data[0].id, id);
+
+    let cancelled = lob_client.cancel_self_mailer(&id).await.unwrap();
+    assert_eq!(cancelled.id, id);
+    assert!(cancelled.deleted);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/self_mailers HTTP/1.1",
+            "GET /v1/self_mailers/sfm_8ffbe811dea49dcf HTTP/1.1",
+            "GET /v1/self_mailers?limit=1 HTTP/1.1",
+            "DELETE /v1/self_mailers/sfm_8ffbe811dea49dcf HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn snap_packs() {
+    use crate::{AddressId, BillingGroupId, SnapPackId, SnapPackSize};
+
+    let snap_pack = mail_piece_json("ord_a2b2c4f3e8d1b9a7", "8.5x11", false);
+    let snap_packs = serde_json::json!({ "data": [snap_pack], "count": 1 });
+    let cancelled = serde_json::json!({ "id": "ord_a2b2c4f3e8d1b9a7", "deleted": true });
+
+    let (base_url, requests) = serve(vec![
+        (200, snap_pack.to_string()),
+        (200, snap_pack.to_string()),
+        (200, snap_packs.to_string()),
+        (200, cancelled.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let to = AddressId::from("adr_d3489cd64c791ab5");
+    let from = AddressId::from("adr_210a8d4b0b76d77b");
+    let billing_group_id = BillingGroupId::from("bg_4bb02b527a9b9ac");
+    let request = lob_client
+        .create_snap_pack()
+        .idempotency_key("ORD-01")
+        .to(&to)
+        .from(&from)
+        .inside("https://example.com/inside.pdf")
+        .outside("https://example.com/outside.pdf")
+        .use_type(UseType::Marketing)
+        .size(SnapPackSize::EightAndHalfByEleven)
+        .mail_type(MailType::UspsFirstClass)
+        .description("Spring offer")
+        .send_date("2024-01-05")
+        .billing_group_id(&billing_group_id)
+        .build();
+    assert_eq!(
+        serde_json::to_value(&request.json_request).unwrap(),
+        serde_json::json!({
+            "to": "adr_d3489cd64c791ab5",
+            "from": "adr_210a8d4b0b76d77b",
+            "inside": "https://example.com/inside.pdf",
+            "outside": "https://example.com/outside.pdf",
+            "size": "8.5x11",
+            "mail_type": "usps_first_class",
+            "description": "Spring offer",
+            "use_type": "marketing",
+            "send_date": "2024-01-05",
+            "billing_group_id": "bg_4bb02b527a9b9ac"
+        })
+    );
+
+    let snap_pack = request.send().await.unwrap();
+    let id = SnapPackId::from("ord_a2b2c4f3e8d1b9a7");
+    assert_eq!(snap_pack.id, id);
+    assert_eq!(snap_pack.size, SnapPackSize::EightAndHalfByEleven);
+    assert_eq!(snap_pack.from.id, from);
+    assert_eq!(
+        snap_pack.expected_delivery_date.as_deref(),
+        Some("2024-01-10")
+    );
+
+    let snap_pack = lob_client.retrieve_snap_pack(&id).await.unwrap();
+    assert_eq!(snap_pack.tracking_events.len(), 1);
+
+    let snap_packs = lob_client.list_snap_packs().send().await.unwrap();
+    assert_eq!(snap_packs.data[0].id, id);
+
+    let cancelled = lob_client.cancel_snap_pack(&id).await.unwrap();
+    assert_eq!(cancelled.id, id);
+    assert!(cancelled.deleted);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/snap_packs HTTP/1.1",
+            "GET /v1/snap_packs/ord_a2b2c4f3e8d1b9a7 HTTP/1.1",
+            "GET /v1/snap_packs HTTP/1.1",
+            "DELETE /v1/snap_packs/ord_a2b2c4f3e8d1b9a7 HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn booklets() {
+    use crate::{AddressId, BookletId, BookletSize, Metadata};
+
+    let booklet = mail_piece_json("bkl_6ac2fbc4c1a0c6e2", "8.375x5.375", false);
+    let booklets = serde_json::json!({ "data": [booklet], "count": 1 });
+    let cancelled = serde_json::json!({ "id": "bkl_6ac2fbc4c1a0c6e2", "deleted": true });
+
+    let (base_url, requests) = serve(vec![
+        (200, booklet.to_string()),
+        (200, booklet.to_string()),
+        (200, booklets.to_string()),
+        (200, cancelled.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let from = AddressId::from("adr_210a8d4b0b76d77b");
+    let request = lob_client
+        .create_booklet()
+        .idempotency_key("BKL-01")
+        .name("Harry Zhang")
+        .address_line_1("210 King St")
+        .city("San Francisco")
+        .state("CA")
+        .zip_code("94107")
+        .from(&from)
+        .file("https://example.com/booklet.pdf")
+        .use_type(UseType::Marketing)
+        .size(BookletSize::EightAndThreeEighthsByFiveAndThreeEighths)
+        .mail_type(MailType::UspsStandard)
+        .description("Spring catalog")
+        .metadata(Metadata(&[("team", "growth")]))
+        .build();
+    assert_eq!(
+        serde_json::to_value(&request.json_request).unwrap(),
+        serde_json::json!({
+            "to": {
+                "name": "Harry Zhang",
+                "address_line1": "210 King St",
+                "address_line2": null,
+                "address_city": "San Francisco",
+                "address_state": "CA",
+                "address_zip": "94107"
+            },
+            "from": "adr_210a8d4b0b76d77b",
+            "file": "https://example.com/booklet.pdf",
+            "size": "8.375x5.375",
+            "mail_type": "usps_standard",
+            "description": "Spring catalog",
+            "use_type": "marketing",
+            "metadata": { "team": "growth" }
+        })
+    );
+
+    let booklet = request.send().await.unwrap();
+    let id = BookletId::from("bkl_6ac2fbc4c1a0c6e2");
+    assert_eq!(booklet.id, id);
+    assert_eq!(
+        booklet.size,
+        BookletSize::EightAndThreeEighthsByFiveAndThreeEighths
+    );
+    assert_eq!(
+        booklet.metadata.get("team").map(AsRef::as_ref),
+        Some("growth")
+    );
+
+    let tracking_events = lob_client.booklet_tracking_events(&id).await.unwrap();
+    assert_eq!(tracking_events[0].id.as_str(), "evnt_9e84094c9368cfb");
+
+    let booklets = lob_client.list_booklets().send().await.unwrap();
+    assert_eq!(booklets.data[0].id, id);
+
+    let cancelled = lob_client.cancel_booklet(&id).await.unwrap();
+    assert_eq!(cancelled.id, id);
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    assert_eq!(
+        requests,
+        [
+            "POST /v1/booklets HTTP/1.1",
+            "GET /v1/booklets/bkl_6ac2fbc4c1a0c6e2 HTTP/1.1",
+            "GET /v1/booklets HTTP/1.1",
+            "DELETE /v1/booklets/bkl_6ac2fbc4c1a0c6e2 HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn templates() {
+    use crate::{Metadata, TemplateEngine, TemplateId, TemplateVersion, TemplateVersionId};
+
+    let version = serde_json::json!({
+        "id": "vrsn_362184d96d9b0c9",
+        "description": "First draft",
+        "html": "<html>Hello {{name}}, your code is {{code}}</html>",
+        "engine": "handlebars",
+        "merge_variables": { "keys": ["name", "code"] },
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z",
+        "object": "version"
+    });
+    let template = serde_json::json!({
+        "id": "tmpl_c94e83ca2cd5121",
+        "description": "Welcome postcard",
+        "versions": [version],
+        "published_version": version,
+        "metadata": { "team": "growth" },
+        "date_created": "2024-01-01T00:00:00.000Z",
+        "date_modified": "2024-01-01T00:00:00.000Z",
+        "object": "template"
+    });
+    let templates = serde_json::json!({ "data": [template], "count": 1 });
+    let deleted = serde_json::json!({ "id": "tmpl_c94e83ca2cd5121", "deleted": true });
+
+    let parsed: TemplateVersion = serde_json::from_value(version.clone()).unwrap();
+    assert_eq!(
+        parsed
+            .merge_variables
+            .keys
+            .iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<&str>>(),
+        ["name", "code"]
+    );
+    assert_eq!(parsed.engine, Some(TemplateEngine::Handlebars));
+
+    let (base_url, requests) = serve_with_bodies(vec![
+        (200, template.to_string()),
+        (200, template.to_string()),
+        (200, template.to_string()),
+        (200, templates.to_string()),
+        (200, deleted.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let template = lob_client
+        .create_template()
+        .html("<html>Hello {{name}}, your code is {{code}}</html>")
+        .description("Welcome postcard")
+        .engine(TemplateEngine::Handlebars)
+        .metadata(Metadata(&[("team", "growth")]))
+        .send()
+        .await
+        .unwrap();
+    let id = TemplateId::from("tmpl_c94e83ca2cd5121");
+    assert_eq!(template.id, id);
+    assert_eq!(
+        template.published_version.id,
+        TemplateVersionId::from("vrsn_362184d96d9b0c9")
+    );
+
+    lob_client
+        .update_template(&id)
+        .description("Welcome postcard, v2")
+        .send()
+        .await
+        .unwrap();
+
+    let version = TemplateVersionId::from("vrsn_362184d96d9b0c9");
+    lob_client
+        .update_template(&id)
+        .published_version(&version)
+        .send()
+        .await
+        .unwrap();
+
+    let templates = lob_client.list_templates().send().await.unwrap();
+    assert_eq!(templates.data[0].id, id);
+
+    let deleted = lob_client.delete_template(&id).await.unwrap();
+    assert_eq!(deleted.id, id);
+
+    let requests: Vec<(String, serde_json::Value)> = requests
+        .try_iter()
+        .map(|(request_line, body)| {
+            let body = match body.is_empty() {
+                true => serde_json::Value::Null,
+                false => serde_json::from_str(&body).unwrap(),
+            };
+            (request_line, body)
+        })
+        .collect();
+    let expected = [
+        (
+            "POST /v1/templates HTTP/1.1",
+            serde_json::json!({
+                "html": "<html>Hello {{name}}, your code is {{code}}</html>",
+                "description": "Welcome postcard",
+                "engine": "handlebars",
+                "metadata": { "team": "growth" }
+            }),
+        ),
+        (
+            "POST /v1/templates/tmpl_c94e83ca2cd5121 HTTP/1.1",
+            serde_json::json!({ "description": "Welcome postcard, v2" }),
+        ),
+        (
+            "POST /v1/templates/tmpl_c94e83ca2cd5121 HTTP/1.1",
+            serde_json::json!({ "published_version": "vrsn_362184d96d9b0c9" }),
+        ),
+        ("GET /v1/templates HTTP/1.1", serde_json::Value::Null),
+        (
+            "DELETE /v1/templates/tmpl_c94e83ca2cd5121 HTTP/1.1",
+            serde_json::Value::Null,
+        ),
+    ];
+    assert_eq!(requests.len(), expected.len());
+    for ((request_line, body), (expected_line, expected_body)) in requests.iter().zip(expected) {
+        assert_eq!(request_line, expected_line);
+        assert_eq!(body, &expected_body);
+    }
+}
+
+#[tokio::test]
+async fn creatives() {
+    use crate::{
+        AddressId, CampaignId, CreativeId, CreativeResourceType, LetterCreative, Metadata,
+        PostcardCreative,
+    };
+
+    let creative = |resource_type: &str| {
+        serde_json::json!({
+            "id": "crv_2a3b096c409b32c",
+            "resource_type": resource_type,
+            "description": "Spring artwork",
+            "campaign_id": "cmp_e05ee61ff80764b",
+            "metadata": { "team": "growth" },
+            "date_created": "2024-01-01T00:00:00.000Z",
+            "date_modified": "2024-01-01T00:00:00.000Z"
+        })
+    };
+
+    let (base_url, requests) = serve_with_bodies(vec![
+        (200, creative("postcard").to_string()),
+        (200, creative("letter").to_string()),
+        (200, creative("letter").to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let campaign = CampaignId::from("cmp_e05ee61ff80764b");
+    let from = AddressId::from("adr_210a8d4b0b76d77b");
+    let postcard = lob_client
+        .create_creative(&campaign)
+        .from(&from)
+        .postcard(PostcardCreative {
+            front: "tmpl_c94e83ca2cd5121",
+            back: "<html>Back</html>",
+            size: Size::FourBySix,
+            mail_type: MailType::UspsFirstClass,
+        })
+        .description("Spring artwork")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(postcard.resource_type, CreativeResourceType::Postcard);
+
+    let letter = lob_client
+        .create_creative(&campaign)
+        .from(&from)
+        .letter(LetterCreative {
+            file: "https://example.com/letter.pdf",
+            color: true,
+            mail_type: MailType::UspsStandard,
+        })
+        .metadata(Metadata(&[("team", "growth")]))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(letter.resource_type, CreativeResourceType::Letter);
+
+    let id = CreativeId::from("crv_2a3b096c409b32c");
+    let updated = lob_client
+        .update_creative(&id)
+        .description("Summer artwork")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(updated.id, id);
+
+    let requests: Vec<(String, serde_json::Value)> = requests
+        .try_iter()
+        .map(|(request_line, body)| (request_line, serde_json::from_str(&body).unwrap()))
+        .collect();
+    let expected = [
+        (
+            "POST /v1/creatives HTTP/1.1",
+            serde_json::json!({
+                "campaign_id": "cmp_e05ee61ff80764b",
+                "from": "adr_210a8d4b0b76d77b",
+                "resource_type": "postcard",
+                "front": "tmpl_c94e83ca2cd5121",
+                "back": "<html>Back</html>",
+                "details": { "size": "4x6", "mail_type": "usps_first_class" },
+                "description": "Spring artwork"
+            }),
+        ),
+        (
+            "POST /v1/creatives HTTP/1.1",
+            serde_json::json!({
+                "campaign_id": "cmp_e05ee61ff80764b",
+                "from": "adr_210a8d4b0b76d77b",
+                "resource_type": "letter",
+                "file": "https://example.com/letter.pdf",
+                "details": { "color": true, "mail_type": "usps_standard" },
+                "metadata": { "team": "growth" }
+            }),
+        ),
+        (
+            "PATCH /v1/creatives/crv_2a3b096c409b32c HTTP/1.1",
+            serde_json::json!({ "description": "Summer artwork" }),
+        ),
+    ];
+    assert_eq!(requests.len(), expected.len());
+    for ((request_line, body), (expected_line, expected_body)) in requests.iter().zip(expected) {
+        assert_eq!(request_line, expected_line);
+        assert_eq!(body, &expected_body);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn uploads() {
+    use crate::{
+        CampaignId, UPLOAD_POLL_INTERVAL, UploadColumnMapping, UploadError, UploadExportType,
+        UploadId,
+    };
+
+    let upload = |original_filename: Option<&str>, state: &str| {
+        serde_json::json!({
+            "id": "upl_71be866e430b11e9",
+            "campaignId": "cmp_e05ee61ff80764b",
+            "state": state,
+            "originalFilename": original_filename,
+            "totalMailpieces": 2,
+            "dateCreated": "2024-01-01T00:00:00.000Z",
+            "dateModified": "2024-01-01T00:00:00.000Z"
+        })
+    };
+    let file =
+        serde_json::json!({ "message": "File uploaded successfully", "filename": "audience.csv" });
+    let export_created =
+        serde_json::json!({ "message": "Export is processing", "exportId": "ex_2dafd758ea2a4b2e" });
+    let export = serde_json::json!({
+        "id": "ex_2dafd758ea2a4b2e",
+        "uploadId": "upl_71be866e430b11e9",
+        "type": "failures",
+        "state": "in_progress",
+        "dateCreated": "2024-01-01T00:00:00.000Z",
+        "dateModified": "2024-01-01T00:00:00.000Z"
+    });
+
+    let (base_url, requests) = serve_with_bodies(vec![
+        (200, upload(None, "Draft").to_string()),
+        (200, file.to_string()),
+        (200, upload(Some("audience.csv"), "Validating").to_string()),
+        (200, upload(Some("audience.csv"), "Validating").to_string()),
+        (200, export_created.to_string()),
+        (200, export.to_string()),
+        (200, export.to_string()),
+    ]);
+    let lob_client = LobClient::builder()
+        .client(reqwest::Client::new())
+        .api_key("test_key")
+        .build_with_base_url(&base_url);
+
+    let campaign = CampaignId::from("cmp_e05ee61ff80764b");
+    let mapping = UploadColumnMapping {
+        name: "Name",
+        address_line1: "Street",
+        address_city: "City",
+        address_state: "State",
+        address_zip: "Zip",
+        company: Some("Company"),
+        merge_variables: &[("case_number", "Case"), ("first_name", "First Name")],
+        ..Default::default()
+    };
+    let upload = lob_client.create_upload(&campaign, &mapping).await.unwrap();
+    let id = UploadId::from("upl_71be866e430b11e9");
+    assert_eq!(upload.id, id);
+
+    let file = lob_client
+        .upload_file(
+            &id,
+            "audience.csv",
+            b"Name,Street\nHarry,210 King St\n".to_vec(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(&*file.filename, "audience.csv");
+
+    let timeout = UPLOAD_POLL_INTERVAL + UPLOAD_POLL_INTERVAL / 2;
+    assert!(matches!(
+        lob_client.wait_for_upload(&id, timeout).await,
+        Err(UploadError::Timeout)
+    ));
+    assert!(matches!(
+        lob_client
+            .export_upload(&id, UploadExportType::Failures, timeout)
+            .await,
+        Err(UploadError::Timeout)
+    ));
+
+    let requests: Vec<_> = requests.try_iter().collect();
+    let request_lines: Vec<&str> = requests
+        .iter()
+        .map(|(request_line, _)| request_line.as_str())
+        .collect();
+    assert_eq!(
+        request_lines,
+        [
+            "POST /v1/uploads HTTP/1.1",
+            "POST /v1/uploads/upl_71be866e430b11e9/file HTTP/1.1",
+            "GET /v1/uploads/upl_71be866e430b11e9 HTTP/1.1",
+            "GET /v1/uploads/upl_71be866e430b11e9 HTTP/1.1",
+            "POST /v1/uploads/upl_71be866e430b11e9/exports HTTP/1.1",
+            "GET /v1/uploads/upl_71be866e430b11e9/exports/ex_2dafd758ea2a4b2e HTTP/1.1",
+            "GET /v1/uploads/upl_71be866e430b11e9/exports/ex_2dafd758ea2a4b2e HTTP/1.1",
+        ]
+    );
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&requests[0].1).unwrap(),
+        serde_json::json!({
+            "campaignId": "cmp_e05ee61ff80764b",
+            "requiredAddressColumnMapping": {
+                "name": "Name",
+                "address_line1": "Street",
+                "address_city": "City",
+                "address_state": "State",
+                "address_zip": "Zip"
+            },
+            "optionalAddressColumnMapping": {
+                "company": "Company",
+                "address_line2": null,
+                "address_country": null
+            },
+            "mergeVariableColumnMapping": {
+                "case_number": "Case",
+                "first_name": "First Name"
+            }
+        })
+    );
+
+    let multipart = &requests[1].1;
+    assert!(multipart.contains(
+        "Content-Disposition: form-data; name=\"file\"; filename=\"audience.csv\"\r\nContent-Type: text/csv\r\n\r\nName,Street\nHarry,210 King St\n"
+    ));
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&requests[4].1).unwrap(),
+        serde_json::json!({ "type": "failures" })
+    );
+}
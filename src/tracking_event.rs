@@ -0,0 +1,104 @@
+use serde::Deserialize;
+
+use crate::id::lob_id;
+
+lob_id!(
+    /// The id of a tracking event, prefixed with `evnt_`
+    TrackingEventId
+);
+
+/// A usps scan of a mail piece on its way to the recipient
+///
+/// Lob has no endpoint listing tracking events on their own, they are embedded in the mail piece
+/// they belong to, e.g. [`Postcard::tracking_events`](crate::Postcard::tracking_events). To backfill
+/// scans missed during an outage, list the mail pieces or the [events](crate::LobClient::list_events)
+/// in a date range with [`ListBuilder::date_created_after`](crate::ListBuilder::date_created_after)
+/// and [`ListBuilder::date_created_before`](crate::ListBuilder::date_created_before).
+#[derive(Clone, Debug, Deserialize)]
+pub struct TrackingEvent {
+    /// the id of the tracking event
+    pub id: TrackingEventId,
+    /// what the scan says happened to the mail piece
+    pub name: TrackingEventName,
+    /// whether the scan is `normal` tracking or `certified` mail tracking
+    #[serde(rename = "type")]
+    pub event_type: Box<str>,
+    /// the zip code of the facility that scanned the mail piece
+    #[serde(default)]
+    pub location: Option<Box<str>>,
+    /// when the mail piece was scanned
+    #[serde(default)]
+    pub time: Option<Box<str>>,
+    /// when lob recorded the tracking event
+    pub date_created: Box<str>,
+}
+
+/// What a usps scan says happened to a mail piece
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum TrackingEventName {
+    /// the mail piece was handed to usps
+    Mailed,
+    /// the mail piece is on its way between usps facilities
+    #[serde(rename = "In Transit")]
+    InTransit,
+    /// the mail piece reached a usps facility near the recipient
+    #[serde(rename = "In Local Area")]
+    InLocalArea,
+    /// the mail piece is sorted and out for delivery
+    #[serde(rename = "Processed for Delivery")]
+    ProcessedForDelivery,
+    /// the mail piece is being sent to a different address, e.g. a forwarding address
+    #[serde(rename = "Re-Routed")]
+    ReRouted,
+    /// the mail piece could not be delivered and is on its way back to the sender
+    #[serde(rename = "Returned to Sender")]
+    ReturnedToSender,
+    /// the mail piece was delivered to the recipient
+    Delivered,
+    /// the mail piece left the us for an international recipient
+    #[serde(rename = "International Exit")]
+    InternationalExit,
+    /// the mail piece is waiting at a post office for the recipient to pick it up
+    #[serde(rename = "Pickup Available")]
+    PickupAvailable,
+    /// usps reported a problem delivering the mail piece
+    Issue,
+    /// a scan this crate does not know about yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl TrackingEventName {
+    /// how far along the usual path to delivery a scan is
+    fn progress(self) -> Option<u8> {
+        match self {
+            Self::Mailed => Some(1),
+            Self::InTransit | Self::ReRouted | Self::InternationalExit => Some(2),
+            Self::InLocalArea => Some(3),
+            Self::ProcessedForDelivery | Self::PickupAvailable => Some(4),
+            Self::Delivered => Some(5),
+            Self::ReturnedToSender | Self::Issue | Self::Unknown => None,
+        }
+    }
+
+    /// whether a mail piece with this scan has reached `target`
+    ///
+    /// Usps does not always scan every step, so a later step on the way to delivery,
+    /// e.g. [`Delivered`](Self::Delivered), also reaches an earlier one like [`Mailed`](Self::Mailed).
+    /// Any other target is only reached by the same scan.
+    pub fn reaches(self, target: Self) -> bool {
+        let on_path = matches!(
+            target,
+            Self::Mailed
+                | Self::InTransit
+                | Self::InLocalArea
+                | Self::ProcessedForDelivery
+                | Self::Delivered
+        );
+
+        match (self.progress(), target.progress()) {
+            (Some(progress), Some(target_progress)) if on_path => progress >= target_progress,
+            _ => self == target,
+        }
+    }
+}
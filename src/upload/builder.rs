@@ -0,0 +1,10 @@
+use std::borrow::Cow;
+
+use crate::{CampaignId, ListBuilder, Upload, UploadError};
+
+impl<'a, 'b, 'c> ListBuilder<'a, 'b, 'c, Upload, UploadError> {
+    /// only returns the uploads of this campaign
+    pub fn campaign_id(self, campaign: &'c CampaignId) -> Self {
+        self.filter("campaignId", Cow::Borrowed(campaign.as_str()))
+    }
+}
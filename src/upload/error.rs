@@ -0,0 +1,55 @@
+use thiserror::Error;
+
+use crate::{ApiError, client::send::LobResponseError};
+
+/// Errors that can occur while performing a Lob upload request.
+#[derive(Debug, Error)]
+pub enum UploadError {
+    /// HTTP or network error from [`reqwest`].
+    #[error("Reqwest: {0}")]
+    Reqwest(
+        #[from]
+        #[source]
+        reqwest::Error,
+    ),
+
+    /// Failed to serialize the json request
+    #[error("Json: {0}")]
+    Serialize(
+        #[from]
+        #[source]
+        serde_json::Error,
+    ),
+
+    /// An Api Error From Lob
+    #[error("Lob: {0}")]
+    Api(
+        #[from]
+        #[source]
+        ApiError,
+    ),
+
+    /// Failed to parse the JSON response.
+    ///
+    /// Contains the parse error and the raw response body.
+    #[error("Json: {0} - {1}")]
+    Json(#[source] serde_json::Error, String),
+
+    /// Failed to read the csv from disk
+    #[error("Io: {0}")]
+    Io(
+        #[from]
+        #[source]
+        std::io::Error,
+    ),
+
+    /// Lob did not finish processing before the timeout elapsed
+    #[error("Timed out waiting for lob to finish processing")]
+    Timeout,
+}
+
+impl LobResponseError for UploadError {
+    fn json(err: serde_json::Error, src: String) -> Self {
+        Self::Json(err, src)
+    }
+}
@@ -0,0 +1,16 @@
+mod builder;
+mod error;
+pub(crate) mod request;
+mod response;
+
+use std::time::Duration;
+
+pub use error::UploadError;
+pub use request::UploadColumnMapping;
+pub use response::{
+    Upload, UploadExport, UploadExportCreated, UploadExportId, UploadExportState, UploadExportType,
+    UploadFile, UploadId, UploadState,
+};
+
+/// How long to wait between checks while lob processes an upload or export
+pub const UPLOAD_POLL_INTERVAL: Duration = Duration::from_secs(2);
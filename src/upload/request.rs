@@ -0,0 +1,84 @@
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use crate::{CampaignId, UploadExportType};
+
+/// Maps the columns of an audience csv onto the fields lob needs.
+///
+/// Each field is the header of the csv column holding that value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UploadColumnMapping<'a> {
+    pub name: &'a str,
+    pub address_line1: &'a str,
+    pub address_city: &'a str,
+    pub address_state: &'a str,
+    pub address_zip: &'a str,
+    pub company: Option<&'a str>,
+    pub address_line2: Option<&'a str>,
+    pub address_country: Option<&'a str>,
+    /// pairs of (merge variable, csv column) used by the campaign's creative
+    pub merge_variables: &'a [(&'a str, &'a str)],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateUploadJson<'a> {
+    pub(crate) campaign_id: &'a CampaignId,
+    pub(crate) required_address_column_mapping: RequiredColumns<'a>,
+    pub(crate) optional_address_column_mapping: OptionalColumns<'a>,
+    pub(crate) merge_variable_column_mapping: MergeVariableColumns<'a>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct RequiredColumns<'a> {
+    name: &'a str,
+    address_line1: &'a str,
+    address_city: &'a str,
+    address_state: &'a str,
+    address_zip: &'a str,
+}
+
+#[derive(Serialize)]
+pub(crate) struct OptionalColumns<'a> {
+    company: Option<&'a str>,
+    address_line2: Option<&'a str>,
+    address_country: Option<&'a str>,
+}
+
+pub(crate) struct MergeVariableColumns<'a>(&'a [(&'a str, &'a str)]);
+
+impl Serialize for MergeVariableColumns<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (variable, column) in self.0 {
+            map.serialize_entry(variable, column)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> CreateUploadJson<'a> {
+    pub(crate) fn new(campaign_id: &'a CampaignId, mapping: &UploadColumnMapping<'a>) -> Self {
+        Self {
+            campaign_id,
+            required_address_column_mapping: RequiredColumns {
+                name: mapping.name,
+                address_line1: mapping.address_line1,
+                address_city: mapping.address_city,
+                address_state: mapping.address_state,
+                address_zip: mapping.address_zip,
+            },
+            optional_address_column_mapping: OptionalColumns {
+                company: mapping.company,
+                address_line2: mapping.address_line2,
+                address_country: mapping.address_country,
+            },
+            merge_variable_column_mapping: MergeVariableColumns(mapping.merge_variables),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct CreateExportJson {
+    #[serde(rename = "type")]
+    pub(crate) export_type: UploadExportType,
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{CampaignId, id::lob_id};
+
+lob_id!(
+    /// The id of an audience upload, prefixed with `upl_`
+    UploadId
+);
+
+lob_id!(
+    /// The id of an upload export, prefixed with `ex_`
+    UploadExportId
+);
+
+/// An audience upload as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Upload {
+    /// the id of the upload
+    pub id: UploadId,
+    /// the campaign the audience is for
+    pub campaign_id: CampaignId,
+    /// where the upload is in lob's processing
+    pub state: UploadState,
+    /// the name of the uploaded csv
+    #[serde(default)]
+    pub original_filename: Option<Box<str>>,
+    /// the name of the csv holding the rows that failed
+    #[serde(default)]
+    pub failure_filename: Option<Box<str>>,
+    /// the number of rows in the uploaded csv
+    #[serde(default)]
+    pub total_mailpieces: u32,
+    /// the number of rows that passed validation
+    #[serde(default)]
+    pub validated_mailpieces: u32,
+    /// the number of rows that failed validation
+    #[serde(default)]
+    pub failed_mailpieces: u32,
+    /// when the upload was created
+    pub date_created: Box<str>,
+    /// when the upload was last modified
+    pub date_modified: Box<str>,
+}
+
+/// Where an upload is in lob's processing
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum UploadState {
+    /// lob is reading the uploaded file
+    Preprocessing,
+    /// the upload has no file yet, or its file has been processed
+    Draft,
+    /// the file is queued for validation
+    #[serde(rename = "Ready for Validation")]
+    ReadyForValidation,
+    /// lob is validating the rows of the file
+    Validating,
+    /// the audience is scheduled to be mailed
+    Scheduled,
+    /// the upload was cancelled
+    Cancelled,
+    /// a state this crate does not know about yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl UploadState {
+    /// whether lob is still working through the uploaded file
+    pub fn is_processing(self) -> bool {
+        matches!(
+            self,
+            Self::Preprocessing | Self::ReadyForValidation | Self::Validating
+        )
+    }
+}
+
+/// The response from lob's api after sending an upload's csv
+#[derive(Clone, Debug, Deserialize)]
+pub struct UploadFile {
+    /// a human readable status message
+    pub message: Box<str>,
+    /// the name lob stored the file under
+    pub filename: Box<str>,
+}
+
+/// Which rows of an upload to export
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadExportType {
+    All,
+    Failures,
+    Successes,
+}
+
+/// The response from lob's api after requesting an export
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadExportCreated {
+    /// a human readable status message
+    pub message: Box<str>,
+    /// the id of the export being generated
+    pub export_id: UploadExportId,
+}
+
+/// An export of an upload's rows as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadExport {
+    /// the id of the export
+    pub id: UploadExportId,
+    /// the upload the export was generated from
+    pub upload_id: UploadId,
+    /// which rows were exported
+    #[serde(rename = "type")]
+    pub export_type: UploadExportType,
+    /// whether the export is ready
+    pub state: UploadExportState,
+    /// a signed link to the exported csv, once the export has succeeded
+    #[serde(default)]
+    pub s3_url: Option<Box<str>>,
+    /// when the export was created
+    pub date_created: Box<str>,
+    /// when the export was last modified
+    pub date_modified: Box<str>,
+}
+
+/// Whether an upload export is ready
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadExportState {
+    InProgress,
+    Failed,
+    Succeeded,
+}
@@ -0,0 +1,7 @@
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::UrlShortenerError;
+pub use request::LinkRequest;
+pub use response::{Domain, DomainId, Link, LinkId};
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Link, Metadata};
+
+/// A short link to create, one at a time with [`LobClient::create_link`](crate::LobClient::create_link)
+/// or in bulk with [`LobClient::create_links`](crate::LobClient::create_links)
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct LinkRequest<'a> {
+    /// where the short link redirects to
+    pub redirect_link: &'a str,
+    /// the domain to serve the short link from, one registered with
+    /// [`LobClient::create_domain`](crate::LobClient::create_domain)
+    pub domain: &'a str,
+    /// the path of the short link on its domain, lob generates one when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<&'a str>,
+    /// the metadata attached to the link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata<'a>>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct CreateDomainJson<'a> {
+    pub(crate) domain: &'a str,
+}
+
+#[derive(Serialize)]
+pub(crate) struct BulkLinksRequest<'a> {
+    pub(crate) links: &'a [LinkRequest<'a>],
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BulkLinksResponse {
+    pub(crate) links: Vec<Link>,
+}
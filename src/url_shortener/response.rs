@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::id::lob_id;
+
+lob_id!(
+    /// The id of a short link domain, prefixed with `dom_`
+    DomainId
+);
+
+lob_id!(
+    /// The id of a short link, prefixed with `lnk_`
+    LinkId
+);
+
+/// A domain registered with lob's url shortener
+#[derive(Clone, Debug, Deserialize)]
+pub struct Domain {
+    /// the id of the domain
+    pub id: DomainId,
+    /// the domain name, e.g. `go.example.com`
+    pub domain: Box<str>,
+    /// whether the domain's dns is set up, e.g. `active` or `pending`
+    #[serde(default)]
+    pub status: Option<Box<str>>,
+    /// when the domain was created
+    pub date_created: Box<str>,
+    /// when the domain was last modified
+    pub date_modified: Box<str>,
+}
+
+/// A short link as returned by lob's api
+#[derive(Clone, Debug, Deserialize)]
+pub struct Link {
+    /// the id of the link
+    pub id: LinkId,
+    /// the domain the link is served from
+    pub domain: Box<str>,
+    /// the full short link to print, e.g. `https://go.example.com/abc123`
+    pub short_link: Box<str>,
+    /// where the short link redirects to
+    pub redirect_link: Box<str>,
+    /// the path of the short link on its domain
+    #[serde(default)]
+    pub slug: Option<Box<str>>,
+    /// how many times the short link has been followed
+    #[serde(default)]
+    pub click_count: u32,
+    /// the metadata attached to the link
+    #[serde(default)]
+    pub metadata: HashMap<Box<str>, Box<str>>,
+    /// when the link was created
+    pub date_created: Box<str>,
+    /// when the link was last modified
+    pub date_modified: Box<str>,
+}
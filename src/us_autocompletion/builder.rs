@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    UsAutocompletion, UsAutocompletionError, client::send::send, constants::APPLICATION_JSON,
+    us_autocompletion::request::JsonRequest,
+};
+
+/// Builder for a US address autocompletion request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct AutocompleteUsAddressBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> AutocompleteUsAddressBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the partial primary address line typed so far, e.g. `185 B`
+    pub fn address_prefix<'c, 'd, 'e, 'f>(
+        self,
+        address_prefix: &'c str,
+    ) -> AutocompleteUsAddressBuilderWithPrefix<'a, 'b, 'c, 'd, 'e, 'f> {
+        AutocompleteUsAddressBuilderWithPrefix {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: JsonRequest {
+                address_prefix,
+                city: None,
+                state: None,
+                zip_code: None,
+            },
+        }
+    }
+}
+
+/// Builder for a US address autocompletion request with an address prefix set.
+pub struct AutocompleteUsAddressBuilderWithPrefix<'a, 'b, 'c, 'd, 'e, 'f> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: JsonRequest<'c, 'd, 'e, 'f>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f> AutocompleteUsAddressBuilderWithPrefix<'a, 'b, 'c, 'd, 'e, 'f> {
+    /// only suggests addresses in this city
+    pub fn city(mut self, city: &'d str) -> Self {
+        self.json_request.city = Some(city);
+        self
+    }
+
+    /// only suggests addresses in this two letter state
+    pub fn state(mut self, state: &'e str) -> Self {
+        self.json_request.state = Some(state);
+        self
+    }
+
+    /// only suggests addresses in this zip code
+    pub fn zip_code(mut self, zip_code: &'f str) -> Self {
+        self.json_request.zip_code = Some(zip_code);
+        self
+    }
+
+    /// sends the autocompletion request
+    pub async fn send(self) -> Result<UsAutocompletion, UsAutocompletionError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
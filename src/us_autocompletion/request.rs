@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(crate) struct JsonRequest<'c, 'd, 'e, 'f> {
+    pub(crate) address_prefix: &'c str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) city: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<&'e str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) zip_code: Option<&'f str>,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+/// The suggestions lob returned for a partial US address
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsAutocompletion {
+    /// the id of the autocompletion
+    #[serde(default)]
+    pub id: Option<Box<str>>,
+    /// up to 10 suggested addresses, most relevant first
+    pub suggestions: Vec<UsAutocompletionSuggestion>,
+}
+
+/// A complete US address suggested for a partial one
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsAutocompletionSuggestion {
+    /// the primary address line, e.g. `185 BERRY ST`
+    pub primary_line: Box<str>,
+    /// the city
+    pub city: Box<str>,
+    /// the two letter state
+    pub state: Box<str>,
+    /// the 5 digit zip code
+    pub zip_code: Box<str>,
+}
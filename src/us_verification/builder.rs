@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    UsVerification, UsVerificationError, client::send::send, constants::APPLICATION_JSON,
+    us_verification::request::JsonRequest,
+};
+
+/// Builder for a US address verification request.
+///
+/// Returned from [`crate::LobClient`](LobClient).
+pub struct VerifyUsAddressBuilder<'a, 'b> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+}
+
+impl<'a, 'b> VerifyUsAddressBuilder<'a, 'b> {
+    pub(crate) fn new(client: reqwest::Client, api_key: &'a str, url: Cow<'b, str>) -> Self {
+        Self {
+            client,
+            api_key,
+            url,
+        }
+    }
+
+    /// sets the primary address line, e.g. `185 Berry St Ste 6100`
+    pub fn primary_line<'c, 'd, 'e, 'f, 'g, 'h, 'i>(
+        self,
+        primary_line: &'c str,
+    ) -> VerifyUsAddressBuilderWithPrimaryLine<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+        VerifyUsAddressBuilderWithPrimaryLine {
+            client: self.client,
+            api_key: self.api_key,
+            url: self.url,
+            json_request: JsonRequest {
+                primary_line,
+                secondary_line: None,
+                urbanization: None,
+                city: None,
+                state: None,
+                zip_code: None,
+                recipient: None,
+            },
+        }
+    }
+}
+
+/// Builder for a US address verification request with a primary line set.
+///
+/// Lob needs either a zip code, or a city and state, alongside the primary line.
+pub struct VerifyUsAddressBuilderWithPrimaryLine<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+    client: reqwest::Client,
+    api_key: &'a str,
+    url: Cow<'b, str>,
+    json_request: JsonRequest<'c, 'd, 'e, 'f, 'g, 'h, 'i>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+    VerifyUsAddressBuilderWithPrimaryLine<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
+{
+    /// sets the secondary address line, e.g. `Ste 6100`
+    pub fn secondary_line(mut self, secondary_line: &'d str) -> Self {
+        self.json_request.secondary_line = Some(secondary_line);
+        self
+    }
+
+    /// sets the urbanization, only used for puerto rico addresses
+    pub fn urbanization(mut self, urbanization: &'e str) -> Self {
+        self.json_request.urbanization = Some(urbanization);
+        self
+    }
+
+    /// sets the city
+    pub fn city(mut self, city: &'f str) -> Self {
+        self.json_request.city = Some(city);
+        self
+    }
+
+    /// sets the two letter state
+    pub fn state(mut self, state: &'g str) -> Self {
+        self.json_request.state = Some(state);
+        self
+    }
+
+    /// sets the zip code, either 5 digits or zip+4
+    pub fn zip_code(mut self, zip_code: &'h str) -> Self {
+        self.json_request.zip_code = Some(zip_code);
+        self
+    }
+
+    /// sets the intended recipient, which lob passes through to the result
+    pub fn recipient(mut self, recipient: &'i str) -> Self {
+        self.json_request.recipient = Some(recipient);
+        self
+    }
+
+    /// sends the verification request
+    pub async fn send(self) -> Result<UsVerification, UsVerificationError> {
+        let request = serde_json::to_string(&self.json_request)?;
+
+        let request = self
+            .client
+            .post(self.url.as_ref())
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .basic_auth::<&str, &str>(self.api_key, None)
+            .body(request);
+
+        send(request).await
+    }
+}
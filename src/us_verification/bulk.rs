@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+/// The most addresses lob accepts in a single bulk US verification request
+///
+/// [`LobClient::verify_us_addresses`](crate::LobClient::verify_us_addresses) splits larger inputs into batches of this size.
+pub const US_VERIFICATION_BATCH_LIMIT: usize = 20;
+
+/// A US address to verify in bulk
+///
+/// Lob needs either a zip code, or a city and state, alongside the primary line.
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct UsVerificationAddress<'a> {
+    /// the primary address line, e.g. `185 Berry St Ste 6100`
+    pub primary_line: &'a str,
+    /// the secondary address line, e.g. `Ste 6100`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_line: Option<&'a str>,
+    /// the urbanization, only used for puerto rico addresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urbanization: Option<&'a str>,
+    /// the city
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    /// the two letter state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+    /// the zip code, either 5 digits or zip+4
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip_code: Option<&'a str>,
+    /// the intended recipient, which lob passes through to the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<&'a str>,
+}
@@ -0,0 +1,11 @@
+pub(crate) mod builder;
+pub(crate) mod bulk;
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use bulk::{US_VERIFICATION_BATCH_LIMIT, UsVerificationAddress};
+pub use error::UsVerificationError;
+pub use response::{
+    Deliverability, DeliverabilityAnalysis, DpvConfirmation, UsComponents, UsVerification,
+};
@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(crate) struct JsonRequest<'c, 'd, 'e, 'f, 'g, 'h, 'i> {
+    pub(crate) primary_line: &'c str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) secondary_line: Option<&'d str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) urbanization: Option<&'e str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) city: Option<&'f str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<&'g str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) zip_code: Option<&'h str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) recipient: Option<&'i str>,
+}
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Deserializer};
+
+/// The result of verifying a US address with lob
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsVerification {
+    /// the id of the verification
+    #[serde(default)]
+    pub id: Option<Box<str>>,
+    /// the intended recipient, if one was provided
+    #[serde(default)]
+    pub recipient: Option<Box<str>>,
+    /// the standardized primary address line, e.g. `185 BERRY ST STE 6100`
+    pub primary_line: Box<str>,
+    /// the standardized secondary address line
+    #[serde(default)]
+    pub secondary_line: Option<Box<str>>,
+    /// the urbanization, only used for puerto rico addresses
+    #[serde(default)]
+    pub urbanization: Option<Box<str>>,
+    /// the standardized last line, e.g. `SAN FRANCISCO CA 94107-1728`
+    pub last_line: Box<str>,
+    /// how deliverable the address is
+    pub deliverability: Deliverability,
+    /// the parts of the standardized address
+    pub components: UsComponents,
+    /// the USPS delivery point validation flags behind `deliverability`
+    pub deliverability_analysis: DeliverabilityAnalysis,
+}
+
+/// Summarizes the deliverability of a verified US address
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Deliverability {
+    /// The address is deliverable by the USPS.
+    Deliverable,
+    /// The address is deliverable, but the secondary information provided is unnecessary.
+    DeliverableUnnecessaryUnit,
+    /// The address is deliverable, but the secondary information provided is incorrect.
+    DeliverableIncorrectUnit,
+    /// The address is deliverable to the building's default address but is missing secondary information.
+    DeliverableMissingUnit,
+    /// The address is not deliverable according to the USPS.
+    Undeliverable,
+}
+
+/// The parts of a standardized US address
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsComponents {
+    /// the house or building number
+    #[serde(default)]
+    pub primary_number: Box<str>,
+    /// the direction before the street name, e.g. `N`
+    #[serde(default)]
+    pub street_predirection: Box<str>,
+    /// the street name
+    #[serde(default)]
+    pub street_name: Box<str>,
+    /// the street suffix, e.g. `ST`
+    #[serde(default)]
+    pub street_suffix: Box<str>,
+    /// the direction after the street name
+    #[serde(default)]
+    pub street_postdirection: Box<str>,
+    /// the secondary designator, e.g. `STE`
+    #[serde(default)]
+    pub secondary_designator: Box<str>,
+    /// the secondary number, e.g. `6100`
+    #[serde(default)]
+    pub secondary_number: Box<str>,
+    /// the city
+    pub city: Box<str>,
+    /// the two letter state
+    pub state: Box<str>,
+    /// the 5 digit zip code
+    pub zip_code: Box<str>,
+    /// the 4 digit zip code extension
+    #[serde(default)]
+    pub zip_code_plus_4: Box<str>,
+    /// the county
+    #[serde(default)]
+    pub county: Box<str>,
+    /// the county fips code
+    #[serde(default)]
+    pub county_fips: Box<str>,
+    /// the carrier route
+    #[serde(default)]
+    pub carrier_route: Box<str>,
+    /// whether the address is `residential` or `commercial`
+    #[serde(default)]
+    pub address_type: Box<str>,
+    /// the latitude of the address
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    /// the longitude of the address
+    #[serde(default)]
+    pub longitude: Option<f64>,
+}
+
+/// The USPS delivery point validation (DPV) flags for a verified US address
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeliverabilityAnalysis {
+    /// whether the USPS confirmed the delivery point
+    pub dpv_confirmation: DpvConfirmation,
+    /// whether the address is a commercial mail receiving agency, like a UPS store
+    #[serde(deserialize_with = "flag")]
+    pub dpv_cmra: Option<bool>,
+    /// whether the address has been vacant for 90 days or more
+    #[serde(deserialize_with = "flag")]
+    pub dpv_vacant: Option<bool>,
+    /// whether the address is active, meaning it receives mail
+    #[serde(default, deserialize_with = "flag")]
+    pub dpv_active: Option<bool>,
+    /// the USPS footnotes explaining the dpv result
+    #[serde(default)]
+    pub dpv_footnotes: Vec<Box<str>>,
+    /// whether the address was flagged by the early warning system, meaning it is too new to be verified
+    #[serde(default)]
+    pub ews_match: bool,
+    /// the locatable address conversion indicator
+    #[serde(default)]
+    pub lacs_indicator: Box<str>,
+    /// the locatable address conversion return code
+    #[serde(default)]
+    pub lacs_return_code: Box<str>,
+    /// the suite link return code
+    #[serde(default)]
+    pub suite_return_code: Box<str>,
+}
+
+/// The USPS delivery point validation result
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum DpvConfirmation {
+    /// The address was confirmed, including its secondary information.
+    #[serde(rename = "Y")]
+    Confirmed,
+    /// The address was confirmed, but the secondary information was not.
+    #[serde(rename = "S")]
+    SecondaryUnconfirmed,
+    /// The address was confirmed, but secondary information is missing.
+    #[serde(rename = "D")]
+    SecondaryMissing,
+    /// The address could not be confirmed.
+    #[serde(rename = "N")]
+    Unconfirmed,
+    /// The address was not submitted for confirmation.
+    #[serde(rename = "")]
+    NotChecked,
+}
+
+/// lob reports dpv flags as `"Y"`, `"N"` or `""` when not applicable
+fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+    let flag = Box::<str>::deserialize(deserializer)?;
+
+    Ok(match flag.as_ref() {
+        "Y" => Some(true),
+        "N" => Some(false),
+        _ => None,
+    })
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::{ApiError, client::send::LobResponseError};
+
+/// Reasons a webhook failed signature verification.
+#[derive(Debug, Error)]
+pub enum WebhookSignatureError {
+    /// The `Lob-Signature-Timestamp` header is not a unix timestamp.
+    #[error("Malformed Lob-Signature-Timestamp")]
+    MalformedTimestamp,
+
+    /// The `Lob-Signature` header is not hex encoded.
+    #[error("Malformed Lob-Signature")]
+    MalformedSignature,
+
+    /// The webhook was signed further from now than the allowed tolerance.
+    ///
+    /// Contains how far the timestamp is from the local clock.
+    #[error("Webhook Timestamp Outside Tolerance: {0:?}")]
+    Expired(Duration),
+
+    /// The signature does not match the body, timestamp and secret.
+    #[error("Webhook Signature Mismatch")]
+    Mismatch,
+}
+
+/// Errors that can occur while retrieving or listing Lob events.
+#[derive(Debug, Error)]
+pub enum EventError {
+    /// HTTP or network error from [`reqwest`].
+    #[error("Reqwest: {0}")]
+    Reqwest(
+        #[from]
+        #[source]
+        reqwest::Error,
+    ),
+
+    /// An Api Error From Lob
+    #[error("Lob: {0}")]
+    Api(
+        #[from]
+        #[source]
+        ApiError,
+    ),
+
+    /// Failed to parse the JSON response.
+    ///
+    /// Contains the parse error and the raw response body.
+    #[error("Json: {0} - {1}")]
+    Json(#[source] serde_json::Error, String),
+}
+
+impl LobResponseError for EventError {
+    fn json(err: serde_json::Error, src: String) -> Self {
+        Self::Json(err, src)
+    }
+}
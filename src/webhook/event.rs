@@ -0,0 +1,226 @@
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+
+use crate::{Address, Booklet, Letter, Postcard, SelfMailer, SnapPack, id::lob_id};
+
+lob_id!(
+    /// The id of a lob event, prefixed with `evt_`
+    EventId
+);
+
+/// A webhook event from lob, with its body parsed into the matching resource model
+///
+/// Event types this crate does not model, including ones lob adds later, deserialize as [`LobEvent::Unknown`] rather than failing.
+/// So do events whose body no longer matches the resource model, for example after lob adds a new field value.
+#[derive(Clone, Debug)]
+pub enum LobEvent {
+    /// a `postcard.*` event
+    Postcard(MailEvent, Event<Postcard>),
+    /// a `self_mailer.*` event
+    SelfMailer(MailEvent, Event<SelfMailer>),
+    /// a `letter.*`, `letter.certified.*` or `letter.return_envelope.*` event
+    Letter(LetterEvent, Event<Letter>),
+    /// a `snap_pack.*` event
+    SnapPack(MailEvent, Event<SnapPack>),
+    /// a `booklet.*` event
+    Booklet(MailEvent, Event<Booklet>),
+    /// an `address.*` event
+    Address(AddressEvent, Event<Address>),
+    /// any other event, or one whose body could not be parsed, along with its `event_type.id` and raw body
+    Unknown(Box<str>, Event<serde_json::Value>),
+}
+
+/// The parts of a lob event shared by every event type
+#[derive(Clone, Debug)]
+pub struct Event<T> {
+    /// the id of the event, the same across redeliveries of the webhook
+    pub id: EventId,
+    /// the id of the resource the event is about
+    pub reference_id: Option<Box<str>>,
+    /// when the event happened
+    pub date_created: Box<str>,
+    /// the resource the event is about
+    pub body: T,
+}
+
+/// What happened to a mail piece
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MailEvent {
+    /// `*.created`, the mail piece was created
+    Created,
+    /// `*.rendered_pdf`, lob finished rendering the pdf
+    RenderedPdf,
+    /// `*.rendered_thumbnails`, lob finished rendering the thumbnails
+    RenderedThumbnails,
+    /// `*.deleted`, the mail piece was cancelled
+    Deleted,
+    /// `*.mailed`, usps accepted the mail piece
+    Mailed,
+    /// `*.in_transit`, the mail piece is moving between usps facilities
+    InTransit,
+    /// `*.in_local_area`, the mail piece reached a facility near the recipient
+    InLocalArea,
+    /// `*.processed_for_delivery`, the mail piece is out for delivery
+    ProcessedForDelivery,
+    /// `*.re-routed`, usps is forwarding the mail piece to a new address
+    ReRouted,
+    /// `*.returned_to_sender`, usps is returning the mail piece
+    ReturnedToSender,
+    /// `*.delivered`, the mail piece was delivered
+    Delivered,
+    /// `*.international_exit`, the mail piece left the us
+    InternationalExit,
+    /// `*.pickup_available`, the mail piece is waiting at a post office
+    PickupAvailable,
+    /// `*.issue`, usps reported a problem delivering the mail piece
+    Issue,
+    /// `*.viewed`, the recipient viewed the mail piece online, e.g. through informed delivery
+    Viewed,
+}
+
+/// What happened to a letter, its certified mail tracking or its return envelope
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LetterEvent {
+    /// a `letter.*` event
+    Letter(MailEvent),
+    /// a `letter.certified.*` event
+    Certified(MailEvent),
+    /// a `letter.return_envelope.*` event
+    ReturnEnvelope(MailEvent),
+}
+
+/// What happened to an address in the address book
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressEvent {
+    /// `address.created`, the address was saved
+    Created,
+    /// `address.deleted`, the address was deleted
+    Deleted,
+}
+
+impl MailEvent {
+    fn from_action(action: &str) -> Option<Self> {
+        Some(match action {
+            "created" => Self::Created,
+            "rendered_pdf" => Self::RenderedPdf,
+            "rendered_thumbnails" => Self::RenderedThumbnails,
+            "deleted" => Self::Deleted,
+            "mailed" => Self::Mailed,
+            "in_transit" => Self::InTransit,
+            "in_local_area" => Self::InLocalArea,
+            "processed_for_delivery" => Self::ProcessedForDelivery,
+            "re-routed" => Self::ReRouted,
+            "returned_to_sender" => Self::ReturnedToSender,
+            "delivered" => Self::Delivered,
+            "international_exit" => Self::InternationalExit,
+            "pickup_available" => Self::PickupAvailable,
+            "issue" => Self::Issue,
+            "viewed" => Self::Viewed,
+            _ => return None,
+        })
+    }
+}
+
+impl LetterEvent {
+    fn from_action(action: &str) -> Option<Self> {
+        match action.split_once('.') {
+            Some(("certified", action)) => MailEvent::from_action(action).map(Self::Certified),
+            Some(("return_envelope", action)) => {
+                MailEvent::from_action(action).map(Self::ReturnEnvelope)
+            }
+            Some(_) => None,
+            None => MailEvent::from_action(action).map(Self::Letter),
+        }
+    }
+}
+
+impl AddressEvent {
+    fn from_action(action: &str) -> Option<Self> {
+        match action {
+            "created" => Some(Self::Created),
+            "deleted" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+
+impl LobEvent {
+    /// the id of the event, the same across redeliveries of the webhook
+    pub fn id(&self) -> &EventId {
+        match self {
+            Self::Postcard(_, event) => &event.id,
+            Self::SelfMailer(_, event) => &event.id,
+            Self::Letter(_, event) => &event.id,
+            Self::SnapPack(_, event) => &event.id,
+            Self::Booklet(_, event) => &event.id,
+            Self::Address(_, event) => &event.id,
+            Self::Unknown(_, event) => &event.id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    id: EventId,
+    #[serde(default)]
+    reference_id: Option<Box<str>>,
+    date_created: Box<str>,
+    event_type: RawEventType,
+    body: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct RawEventType {
+    id: Box<str>,
+}
+
+impl RawEvent {
+    /// parses the body into `T`, or `None` if it does not match the model
+    fn typed<T: DeserializeOwned>(&self) -> Option<Event<T>> {
+        Some(Event {
+            id: self.id.clone(),
+            reference_id: self.reference_id.clone(),
+            date_created: self.date_created.clone(),
+            body: T::deserialize(&self.body).ok()?,
+        })
+    }
+
+    fn typed_lob_event(&self) -> Option<LobEvent> {
+        let (resource, action) = self
+            .event_type
+            .id
+            .split_once('.')
+            .unwrap_or((&self.event_type.id, ""));
+
+        Some(match resource {
+            "postcard" => LobEvent::Postcard(MailEvent::from_action(action)?, self.typed()?),
+            "self_mailer" => LobEvent::SelfMailer(MailEvent::from_action(action)?, self.typed()?),
+            "letter" => LobEvent::Letter(LetterEvent::from_action(action)?, self.typed()?),
+            "snap_pack" => LobEvent::SnapPack(MailEvent::from_action(action)?, self.typed()?),
+            "booklet" => LobEvent::Booklet(MailEvent::from_action(action)?, self.typed()?),
+            "address" => LobEvent::Address(AddressEvent::from_action(action)?, self.typed()?),
+            _ => return None,
+        })
+    }
+
+    fn into_lob_event(self) -> LobEvent {
+        if let Some(event) = self.typed_lob_event() {
+            return event;
+        }
+
+        LobEvent::Unknown(
+            self.event_type.id,
+            Event {
+                id: self.id,
+                reference_id: self.reference_id,
+                date_created: self.date_created,
+                body: self.body,
+            },
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for LobEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(RawEvent::deserialize(deserializer)?.into_lob_event())
+    }
+}
@@ -0,0 +1,14 @@
+mod error;
+mod event;
+#[cfg(feature = "axum")]
+mod receiver;
+mod signature;
+
+pub use error::{EventError, WebhookSignatureError};
+pub use event::{AddressEvent, Event, EventId, LetterEvent, LobEvent, MailEvent};
+#[cfg(feature = "axum")]
+pub use receiver::{DEFAULT_WEBHOOK_DEDUPE_CAPACITY, WebhookReceiver};
+pub use signature::{
+    DEFAULT_WEBHOOK_TOLERANCE, LOB_SIGNATURE, LOB_SIGNATURE_TIMESTAMP, verify_webhook_signature,
+    verify_webhook_signature_at,
+};
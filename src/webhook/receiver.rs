@@ -0,0 +1,196 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use axum::{
+    Router,
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+};
+
+use crate::{
+    DEFAULT_WEBHOOK_TOLERANCE, EventId, LOB_SIGNATURE, LOB_SIGNATURE_TIMESTAMP, LobEvent,
+    verify_webhook_signature,
+};
+
+/// How many recent event ids a [`WebhookReceiver`] remembers by default
+pub const DEFAULT_WEBHOOK_DEDUPE_CAPACITY: usize = 10_000;
+
+/// A ready-made endpoint for lob webhooks.
+///
+/// Each request is checked against the webhook secret, parsed into a [`LobEvent`] and handed to the callback. Events lob redelivers after they were handled are acknowledged without calling the callback again. Lob retries any webhook not answered with a 2xx, so a callback returning `Err` answers 500 and the event is handled again on the retry. A redelivery arriving while the callback is still running for the same event answers 409, so lob retries it once the outcome is known.
+pub struct WebhookReceiver<F> {
+    secret: Box<[u8]>,
+    tolerance: Duration,
+    seen: Mutex<SeenEvents>,
+    callback: F,
+}
+
+struct SeenEvents {
+    capacity: usize,
+    ids: HashSet<EventId>,
+    order: VecDeque<EventId>,
+    in_flight: HashSet<EventId>,
+}
+
+/// Whether an event is new, being handled, or already handled
+enum Delivery {
+    New,
+    InFlight,
+    Seen,
+}
+
+/// Marks an event as in flight until it is dropped or [`completed`](InFlight::complete)
+struct InFlight<'r> {
+    seen: &'r Mutex<SeenEvents>,
+    id: EventId,
+}
+
+impl<F, Fut, E> WebhookReceiver<F>
+where
+    F: Fn(LobEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: 'static,
+{
+    /// creates a receiver verifying webhooks with `secret` and dispatching them to `callback`
+    pub fn new(secret: impl Into<Box<[u8]>>, callback: F) -> Self {
+        Self {
+            secret: secret.into(),
+            tolerance: DEFAULT_WEBHOOK_TOLERANCE,
+            seen: Mutex::new(SeenEvents {
+                capacity: DEFAULT_WEBHOOK_DEDUPE_CAPACITY,
+                ids: HashSet::new(),
+                order: VecDeque::new(),
+                in_flight: HashSet::new(),
+            }),
+            callback,
+        }
+    }
+
+    /// sets how far a webhook's timestamp may be from the local clock
+    pub fn tolerance(self, tolerance: Duration) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// sets how many recent event ids are remembered to drop redeliveries
+    pub fn dedupe_capacity(self, capacity: usize) -> Self {
+        lock(&self.seen).capacity = capacity;
+        self
+    }
+
+    /// handles one webhook request, returning the status code to answer lob with
+    ///
+    /// Useful when serving webhooks from something other than [`WebhookReceiver::into_router`].
+    pub async fn handle(&self, headers: &HeaderMap, body: &[u8]) -> StatusCode {
+        let signature = headers.get(LOB_SIGNATURE).and_then(|v| v.to_str().ok());
+        let timestamp = headers
+            .get(LOB_SIGNATURE_TIMESTAMP)
+            .and_then(|v| v.to_str().ok());
+        let (Some(signature), Some(timestamp)) = (signature, timestamp) else {
+            return StatusCode::UNAUTHORIZED;
+        };
+
+        if verify_webhook_signature(&self.secret, body, signature, timestamp, self.tolerance)
+            .is_err()
+        {
+            return StatusCode::UNAUTHORIZED;
+        }
+
+        // a signed body that is not an event at all would fail the same way on every retry
+        let Ok(event) = serde_json::from_slice::<LobEvent>(body) else {
+            return StatusCode::OK;
+        };
+
+        let id = event.id().clone();
+        match lock(&self.seen).begin(&id) {
+            Delivery::New => {}
+            Delivery::InFlight => return StatusCode::CONFLICT,
+            Delivery::Seen => return StatusCode::OK,
+        }
+        let in_flight = InFlight {
+            seen: &self.seen,
+            id,
+        };
+
+        match (self.callback)(event).await {
+            Ok(()) => {
+                in_flight.complete();
+                StatusCode::OK
+            }
+            Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// a router accepting lob webhooks with `POST /`, nest it at the path given to lob
+    pub fn into_router<S>(self) -> Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        Router::new()
+            .route("/", post(receive::<F, Fut, E>))
+            .with_state(Arc::new(self))
+    }
+}
+
+async fn receive<F, Fut, E>(
+    State(receiver): State<Arc<WebhookReceiver<F>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode
+where
+    F: Fn(LobEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: 'static,
+{
+    receiver.handle(&headers, &body).await
+}
+
+fn lock(seen: &Mutex<SeenEvents>) -> MutexGuard<'_, SeenEvents> {
+    seen.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl SeenEvents {
+    /// marks `id` as in flight if it is neither being handled nor already handled
+    fn begin(&mut self, id: &EventId) -> Delivery {
+        if self.ids.contains(id) {
+            return Delivery::Seen;
+        }
+        match self.in_flight.insert(id.clone()) {
+            true => Delivery::New,
+            false => Delivery::InFlight,
+        }
+    }
+
+    /// remembers a handled `id`, forgetting the oldest once over capacity
+    fn insert(&mut self, id: EventId) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.ids.insert(id.clone());
+        self.order.push_back(id);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl InFlight<'_> {
+    /// the callback succeeded, remember the event so redeliveries are dropped
+    fn complete(self) {
+        lock(self.seen).insert(self.id.clone());
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        lock(self.seen).in_flight.remove(&self.id);
+    }
+}
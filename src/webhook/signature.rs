@@ -0,0 +1,84 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::WebhookSignatureError;
+
+/// The header holding the hex encoded HMAC-SHA256 signature of a webhook
+pub const LOB_SIGNATURE: &str = "Lob-Signature";
+
+/// The header holding when lob signed a webhook
+pub const LOB_SIGNATURE_TIMESTAMP: &str = "Lob-Signature-Timestamp";
+
+/// How far a webhook's timestamp may be from the local clock by default
+pub const DEFAULT_WEBHOOK_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// Timestamps at or above this are read as milliseconds, below it as seconds
+const MILLISECOND_TIMESTAMP_THRESHOLD: u64 = 100_000_000_000;
+
+/// Checks a webhook from lob was signed with `secret` and signed within `tolerance` of now.
+///
+/// `body` must be the raw request body, before any json parsing. `signature` and `timestamp` are the values of the [`LOB_SIGNATURE`] and [`LOB_SIGNATURE_TIMESTAMP`] headers.
+pub fn verify_webhook_signature(
+    secret: &[u8],
+    body: &[u8],
+    signature: &str,
+    timestamp: &str,
+    tolerance: Duration,
+) -> Result<(), WebhookSignatureError> {
+    verify_webhook_signature_at(
+        secret,
+        body,
+        signature,
+        timestamp,
+        tolerance,
+        SystemTime::now(),
+    )
+}
+
+/// Like [`verify_webhook_signature`], but checks the timestamp against `now` instead of the local clock.
+pub fn verify_webhook_signature_at(
+    secret: &[u8],
+    body: &[u8],
+    signature: &str,
+    timestamp: &str,
+    tolerance: Duration,
+    now: SystemTime,
+) -> Result<(), WebhookSignatureError> {
+    let signed_at = parse_timestamp(timestamp)?;
+    let skew = match now.duration_since(signed_at) {
+        Ok(elapsed) => elapsed,
+        Err(err) => err.duration(),
+    };
+    if skew > tolerance {
+        return Err(WebhookSignatureError::Expired(skew));
+    }
+
+    let signature =
+        hex::decode(signature.trim()).map_err(|_| WebhookSignatureError::MalformedSignature)?;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("hmac accepts keys of any length");
+    mac.update(timestamp.trim().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookSignatureError::Mismatch)
+}
+
+fn parse_timestamp(timestamp: &str) -> Result<SystemTime, WebhookSignatureError> {
+    let timestamp: u64 = timestamp
+        .trim()
+        .parse()
+        .map_err(|_| WebhookSignatureError::MalformedTimestamp)?;
+
+    let since_epoch = match timestamp >= MILLISECOND_TIMESTAMP_THRESHOLD {
+        true => Duration::from_millis(timestamp),
+        false => Duration::from_secs(timestamp),
+    };
+
+    UNIX_EPOCH
+        .checked_add(since_epoch)
+        .ok_or(WebhookSignatureError::MalformedTimestamp)
+}
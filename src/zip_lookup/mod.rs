@@ -0,0 +1,6 @@
+mod error;
+pub(crate) mod request;
+mod response;
+
+pub use error::ZipLookupError;
+pub use response::{ZipCodeType, ZipLookup, ZipLookupCity};
@@ -0,0 +1,6 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(crate) struct ZipLookupRequest<'c> {
+    pub(crate) zip_code: &'c str,
+}
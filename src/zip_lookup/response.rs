@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+/// The cities and counties lob found for a US zip code
+#[derive(Clone, Debug, Deserialize)]
+pub struct ZipLookup {
+    /// the id of the lookup
+    #[serde(default)]
+    pub id: Option<Box<str>>,
+    /// the 5 digit zip code that was looked up
+    pub zip_code: Box<str>,
+    /// what the zip code is used for
+    pub zip_code_type: ZipCodeType,
+    /// every city the zip code covers, with the USPS preferred city marked
+    pub cities: Vec<ZipLookupCity>,
+}
+
+/// What a US zip code is used for
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ZipCodeType {
+    /// A standard zip code, delivered to street addresses.
+    Standard,
+    /// A zip code used only for PO boxes.
+    PoBox,
+    /// A zip code assigned to a single high volume address.
+    Unique,
+    /// A zip code used for military addresses.
+    Military,
+    /// The zip code does not exist.
+    #[serde(rename = "")]
+    Invalid,
+}
+
+/// A city covered by a US zip code
+#[derive(Clone, Debug, Deserialize)]
+pub struct ZipLookupCity {
+    /// the city
+    pub city: Box<str>,
+    /// the two letter state
+    pub state: Box<str>,
+    /// the county
+    pub county: Box<str>,
+    /// the county fips code
+    pub county_fips: Box<str>,
+    /// whether this is the USPS preferred city for the zip code
+    pub preferred: bool,
+}